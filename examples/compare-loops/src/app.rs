@@ -33,6 +33,7 @@ impl Component for App {
             "std_with_signal",
             "comp_copy_with_signal",
             "comp_with_signal",
+            "comp_vec_with_signal",
         ];
 
         let states = actions
@@ -207,6 +208,21 @@ impl Component for App {
                             duration={self.states["comp_with_signal"].duration()}
                         />
                     </Panel>
+
+                    <Panel
+                        title="Loop Component"
+                        subtitle="with signal vector"
+                        group={group.clone()}
+                        action={mk_do_action("comp_vec_with_signal")}
+                        list={self.states["comp_vec_with_signal"].get_list()}
+                        disabled={self.states["comp_vec_with_signal"].disabled()}
+                        duration={self.states["comp_vec_with_signal"].duration()}
+                    >
+                        <panels::comp_with_signal_vec::Panel
+                            values={self.states["comp_vec_with_signal"].get_vec()}
+                            duration={self.states["comp_vec_with_signal"].duration()}
+                        />
+                    </Panel>
                 </Block>
             </Container>
         }
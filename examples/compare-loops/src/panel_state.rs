@@ -1,14 +1,20 @@
-use super::{data::DataList, button_group::ButtonGroup, action_kind::ActionKind};
+use super::{
+    data::{DataList, Record},
+    button_group::ButtonGroup,
+    action_kind::ActionKind,
+};
 use yew_reactor::{
-    signal::{Runtime, Signal},
+    signal::{Runtime, Signal, SignalVec},
     duration::DurationInfo,
 };
+use rand::seq::IteratorRandom;
 use std::sync::Arc;
 
 pub struct PanelState {
     name:     String,
     runtime:  Arc<Runtime>,
     list:     Signal<DataList>,
+    vec:      SignalVec<Record>,
     duration: Signal<DurationInfo>,
     group:    Signal<ButtonGroup>,
     disabled: Signal<bool>,
@@ -23,6 +29,8 @@ impl PanelState {
             Arc::clone(&runtime).create_signal(list)
         };
 
+        let vec = Arc::clone(&runtime).create_signal_vec(list.with(|list| list.values().cloned().collect()));
+
         let duration = Arc::clone(&runtime).create_signal(DurationInfo::new());
         let disabled = Arc::clone(&runtime).create_signal(false);
 
@@ -63,6 +71,7 @@ impl PanelState {
             runtime,
             duration,
             list,
+            vec,
             group,
             disabled,
         }
@@ -78,6 +87,16 @@ impl PanelState {
 
     pub fn do_action(&self) {
         let name = self.name.clone();
+
+        // the vector panel applies each action item by item, the list only follows the record count
+        if name.starts_with("comp_vec_") {
+            if let Some(kind) = self.group.with(|group| group.selection().as_ref().cloned()) {
+                self.do_vec_action(kind);
+            }
+
+            return;
+        }
+
         match self.group.with(|group| group.selection().as_ref().cloned()) {
             Some(ActionKind::Add(n)) => {
                 if name.starts_with("comp_copy_") {
@@ -169,6 +188,71 @@ impl PanelState {
         }
     }
 
+    fn do_vec_action(&self, kind: ActionKind) {
+        let mut rng = rand::thread_rng();
+        let sz = self.vec.len();
+
+        match kind {
+            ActionKind::Add(n) => {
+                for _ in 0..n {
+                    let record = Record::generate();
+
+                    self.list.update(|list| list.insert(record.clone()));
+                    self.vec.push(record);
+                }
+            }
+
+            ActionKind::Del(n) => {
+                let mut indexes = (0..sz).choose_multiple(&mut rng, n.min(sz));
+
+                indexes.sort_unstable_by(|a, b| b.cmp(a));
+                for index in indexes {
+                    if let Some(item) = self.vec.remove(index) {
+                        let id = item.with(|record| record.id());
+
+                        self.list.update(|list| {
+                            list.remove(id);
+                        });
+                    }
+                }
+            }
+
+            ActionKind::ChangeOccupation => {
+                for index in (0..sz).choose_multiple(&mut rng, if sz > 3 { sz / 2 } else { 1 }) {
+                    if let Some(item) = self.vec.item(index) {
+                        item.update(|record| record.change_occupation());
+                    }
+                }
+            }
+
+            ActionKind::ChangeDescription => {
+                for index in (0..sz).choose_multiple(&mut rng, if sz > 3 { sz / 2 } else { 1 }) {
+                    if let Some(item) = self.vec.item(index) {
+                        item.update(|record| record.change_description());
+                    }
+                }
+            }
+
+            ActionKind::Rotate => {
+                if sz > 1 {
+                    self.vec.move_item(0, sz - 1);
+                }
+            }
+
+            ActionKind::Reset => {
+                self.vec.clear();
+                self.list.update(|list| list.clear());
+
+                for _ in 0..5 {
+                    let record = Record::generate();
+
+                    self.list.update(|list| list.insert(record.clone()));
+                    self.vec.push(record);
+                }
+            }
+        }
+    }
+
     pub fn set_list(&self, list: DataList) {
         self.list.set(list);
     }
@@ -177,6 +261,10 @@ impl PanelState {
         self.list.clone()
     }
 
+    pub fn get_vec(&self) -> SignalVec<Record> {
+        self.vec.clone()
+    }
+
     pub fn duration(&self) -> Signal<DurationInfo> {
         self.duration.clone()
     }
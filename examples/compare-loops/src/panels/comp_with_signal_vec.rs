@@ -0,0 +1,38 @@
+use crate::{data::Record, card::LoopCard};
+use yew_reactor::{signal::SignalVec, components::For, duration::DurationInfo, signal::Signal};
+use yew::{Properties, Html, html, Component, Context};
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct Props {
+    pub values:   SignalVec<Record>,
+    pub duration: Signal<DurationInfo>,
+}
+
+pub struct Panel;
+
+impl Component for Panel {
+    type Message = ();
+    type Properties = Props;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+
+        html! {
+             <ul>
+                 <For<Record, SignalVec<Record>> values={props.values.clone()} duration={props.duration.clone()}>
+                     <LoopCard />
+                 </For<Record, SignalVec<Record>>>
+             </ul>
+        }
+    }
+
+    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
+        ctx.props().duration.update(|d| {
+            d.end();
+        });
+    }
+}
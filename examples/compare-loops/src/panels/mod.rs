@@ -1,4 +1,5 @@
 pub mod comp_with_signal;
+pub mod comp_with_signal_vec;
 pub mod std_with_key;
 pub mod std_with_signal;
 pub mod std_without_key;
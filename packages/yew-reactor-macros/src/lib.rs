@@ -0,0 +1,15 @@
+mod signal_struct;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+/// Generates a `<Name>Signals` struct holding one signal per field,
+/// fields marked with `#[signal_struct(nested)]` are held by the signal struct of their own type
+#[proc_macro_derive(SignalStruct, attributes(signal_struct))]
+pub fn derive_signal_struct(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    signal_struct::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,168 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Error, Field, Fields, Result};
+
+struct StructField<'a> {
+    field:  &'a Field,
+    nested: bool,
+}
+
+impl<'a> StructField<'a> {
+    fn parse(field: &'a Field) -> Result<Self> {
+        let mut nested = false;
+
+        for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("signal_struct")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("nested") {
+                    nested = true;
+
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported signal_struct attribute, expected `nested`"))
+                }
+            })?;
+        }
+
+        Ok(Self {
+            field,
+            nested,
+        })
+    }
+}
+
+pub(crate) fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let name = &input.ident;
+    let vis = &input.vis;
+    let struct_name = format_ident!("{name}Signals");
+
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "SignalStruct cannot be derived for generic structs",
+        ));
+    }
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) if !fields.named.is_empty() => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    name,
+                    "SignalStruct can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => return Err(Error::new_spanned(name, "SignalStruct can only be derived for structs")),
+    };
+
+    let fields = fields.iter().map(StructField::parse).collect::<Result<Vec<_>>>()?;
+    let first = fields[0].field.ident.as_ref().unwrap();
+    let first_runtime = if fields[0].nested {
+        quote! { ::yew_reactor::signal_struct::SignalStruct::runtime(&self.#first) }
+    } else {
+        quote! { self.#first.runtime() }
+    };
+
+    let mut decls = Vec::with_capacity(fields.len());
+    let mut inits = Vec::with_capacity(fields.len());
+    let mut snapshots = Vec::with_capacity(fields.len());
+    let mut updates = Vec::with_capacity(fields.len());
+
+    for StructField {
+        field,
+        nested,
+    } in &fields
+    {
+        let ident = field.ident.as_ref().unwrap();
+        let field_vis = &field.vis;
+        let ty = &field.ty;
+
+        if *nested {
+            decls.push(quote! {
+                #field_vis #ident: <#ty as ::yew_reactor::signal_struct::IntoSignalStruct>::Signals
+            });
+            inits.push(quote! {
+                #ident: ::yew_reactor::signal_struct::IntoSignalStruct::into_signals(value.#ident, rt)
+            });
+            snapshots.push(quote! {
+                #ident: ::yew_reactor::signal_struct::SignalStruct::snapshot(&self.#ident)
+            });
+            updates.push(quote! {
+                ::yew_reactor::signal_struct::SignalStruct::set_all(&self.#ident, value.#ident);
+            });
+        } else {
+            decls.push(quote! {
+                #field_vis #ident: ::yew_reactor::signal::Signal<#ty>
+            });
+            inits.push(quote! {
+                #ident: ::std::sync::Arc::clone(rt).create_signal(value.#ident)
+            });
+            snapshots.push(quote! {
+                #ident: self.#ident.get()
+            });
+            updates.push(quote! {
+                {
+                    let new_value = value.#ident;
+
+                    self.#ident.update_if(move |v| {
+                        let changed = *v != new_value;
+                        if changed {
+                            *v = new_value;
+                        }
+
+                        changed
+                    });
+                }
+            });
+        }
+    }
+
+    Ok(quote! {
+        #[derive(Clone)]
+        #vis struct #struct_name {
+            #(#decls,)*
+        }
+
+        impl #struct_name {
+            pub fn new(rt: &::std::sync::Arc<::yew_reactor::signal::Runtime>, value: #name) -> Self {
+                Self {
+                    #(#inits,)*
+                }
+            }
+
+            pub fn snapshot(&self) -> #name {
+                #name {
+                    #(#snapshots,)*
+                }
+            }
+
+            pub fn set_all(&self, value: #name) {
+                #(#updates)*
+            }
+        }
+
+        impl ::yew_reactor::signal_struct::SignalStruct for #struct_name {
+            type Value = #name;
+
+            fn runtime(&self) -> ::std::sync::Arc<::yew_reactor::signal::Runtime> {
+                #first_runtime
+            }
+
+            fn snapshot(&self) -> #name {
+                #struct_name::snapshot(self)
+            }
+
+            fn set_all(&self, value: #name) {
+                #struct_name::set_all(self, value)
+            }
+        }
+
+        impl ::yew_reactor::signal_struct::IntoSignalStruct for #name {
+            type Signals = #struct_name;
+
+            fn into_signals(self, rt: &::std::sync::Arc<::yew_reactor::signal::Runtime>) -> #struct_name {
+                #struct_name::new(rt, self)
+            }
+        }
+    })
+}
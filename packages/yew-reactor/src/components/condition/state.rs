@@ -1,4 +1,4 @@
-use crate::{signal::Signal, components::AsBool};
+use crate::{signal::Signal, components::AsBool, effect};
 use yew::{Component, Context, Html, Properties};
 use std::marker::PhantomData;
 
@@ -30,16 +30,11 @@ impl<T: AsBool, C: Component<Message = Message, Properties = Props<T>>> Conditio
     pub(super) fn create(ctx: &Context<C>) -> Self {
         let signal = ctx.props().when.clone();
 
-        {
-            let scope = ctx.link().clone();
-            let condition = signal.clone();
+        effect!([condition = signal, scope = ctx.link()], {
+            let value = condition.with(AsBool::as_bool);
 
-            condition.runtime().create_effect(move || {
-                let value = condition.with(AsBool::as_bool);
-
-                scope.send_message(Message::SetValue(value));
-            });
-        }
+            scope.send_message(Message::SetValue(value));
+        });
 
         Self {
             condition: false,
@@ -0,0 +1,345 @@
+use crate::signal::{Change, KeyedCollection, ReactiveMap, Signal, SignalVec, Subscription, VecDiff};
+use indexmap::IndexSet;
+use std::{
+    hash::Hash,
+    sync::{Arc, Mutex},
+};
+
+/// Element of a loop, identified by a key which stays the same while the item lives
+pub struct LoopItem<T: 'static> {
+    pub key:   String,
+    pub value: Signal<Option<T>>,
+}
+
+/// Change to apply to the elements of a loop
+pub enum LoopChange<T: 'static> {
+    /// All the elements are rebuilt
+    Reset,
+    Push(LoopItem<T>),
+    Insert(usize, LoopItem<T>),
+    Remove(usize),
+    Move(usize, usize),
+    Swap(usize, usize),
+    Clear,
+}
+
+impl<T: 'static> LoopChange<T> {
+    /// Applies the change to the elements of the loop, only the affected elements are created or removed
+    pub fn apply<E>(
+        self,
+        elements: &mut Vec<E>,
+        make_element: impl Fn(LoopItem<T>) -> E,
+        make_elements: impl FnOnce() -> Vec<E>,
+    ) {
+        match self {
+            LoopChange::Reset => {
+                *elements = make_elements();
+            }
+            LoopChange::Push(item) => {
+                elements.push(make_element(item));
+            }
+            LoopChange::Insert(index, item) => {
+                elements.insert(index, make_element(item));
+            }
+            LoopChange::Remove(index) => {
+                drop(elements.remove(index));
+            }
+            LoopChange::Move(from, to) => {
+                let element = elements.remove(from);
+
+                elements.insert(to, element);
+            }
+            LoopChange::Swap(a, b) => {
+                elements.swap(a, b);
+            }
+            LoopChange::Clear => {
+                elements.clear();
+            }
+        }
+    }
+}
+
+/// Collection iterated by the `For` component
+pub trait LoopCollection: 'static {
+    type Value: Clone + Default + PartialEq + 'static;
+    type Source: Clone + PartialEq + 'static;
+
+    fn make_items(source: &Self::Source) -> Vec<LoopItem<Self::Value>>;
+
+    /// Sends the changes of the collection until the returned subscription is dropped
+    fn watch(source: &Self::Source, f: impl Fn(LoopChange<Self::Value>) + 'static) -> Subscription;
+
+    fn link(source: &Self::Source, target: &Self::Source);
+}
+
+impl<T, C> LoopCollection for C
+where
+    T: Clone + Default + PartialEq + 'static,
+    C: KeyedCollection<Value = T>,
+{
+    type Source = Signal<C>;
+    type Value = T;
+
+    fn make_items(source: &Signal<C>) -> Vec<LoopItem<T>> {
+        let runtime = source.runtime();
+
+        source.with(|c| {
+            c.iter_keys()
+                .map(|key| LoopItem {
+                    value: Arc::clone(&runtime).create_keyed_signal(source.clone(), &key),
+                    key,
+                })
+                .collect()
+        })
+    }
+
+    fn watch(source: &Signal<C>, f: impl Fn(LoopChange<T>) + 'static) -> Subscription {
+        source.on_notify(move || f(LoopChange::Reset))
+    }
+
+    fn link(source: &Signal<C>, target: &Signal<C>) {
+        source.link_to(target);
+    }
+}
+
+impl<T: Clone + Default + PartialEq + 'static> LoopCollection for SignalVec<T> {
+    type Source = SignalVec<T>;
+    type Value = T;
+
+    fn make_items(source: &SignalVec<T>) -> Vec<LoopItem<T>> {
+        source.items().iter().map(make_vec_item).collect()
+    }
+
+    fn watch(source: &SignalVec<T>, f: impl Fn(LoopChange<T>) + 'static) -> Subscription {
+        source.on_diff(move |diff| {
+            let change = match diff {
+                VecDiff::Push(item) => LoopChange::Push(make_vec_item(item)),
+                VecDiff::Insert {
+                    index,
+                    item,
+                } => LoopChange::Insert(*index, make_vec_item(item)),
+                VecDiff::Remove {
+                    index,
+                } => LoopChange::Remove(*index),
+                VecDiff::Move {
+                    from,
+                    to,
+                } => LoopChange::Move(*from, *to),
+                VecDiff::Swap {
+                    a,
+                    b,
+                } => LoopChange::Swap(*a, *b),
+                VecDiff::Clear => LoopChange::Clear,
+
+                // the element of a replaced item follows its signal
+                VecDiff::Replace {
+                    ..
+                } => return,
+            };
+
+            f(change);
+        })
+    }
+
+    fn link(source: &SignalVec<T>, target: &SignalVec<T>) {
+        source.link_to(target);
+    }
+}
+
+impl<K, V> LoopCollection for ReactiveMap<K, V>
+where
+    K: Clone + Eq + Hash + ToString + 'static,
+    V: Clone + Default + PartialEq + 'static,
+{
+    type Source = ReactiveMap<K, V>;
+    type Value = V;
+
+    fn make_items(source: &ReactiveMap<K, V>) -> Vec<LoopItem<V>> {
+        source
+            .keys()
+            .iter()
+            .filter_map(|key| make_map_item(source, key))
+            .collect()
+    }
+
+    fn watch(source: &ReactiveMap<K, V>, f: impl Fn(LoopChange<V>) + 'static) -> Subscription {
+        // mirror of the keys when the watch starts, to find the index of a removed key
+        let keys = Mutex::new(source.keys_signal().untracked_with(IndexSet::clone));
+        let map = source.clone();
+
+        source
+            .runtime()
+            .create_change_effect(&source.keys_signal(), move |change: &Change<K>| {
+                let change = match change {
+                    Change::Inserted(key) => {
+                        if !keys.lock().unwrap().insert(key.clone()) {
+                            return;
+                        }
+
+                        match make_map_item(&map, key) {
+                            Some(item) => LoopChange::Push(item),
+                            None => LoopChange::Reset,
+                        }
+                    }
+                    Change::Removed(key) => match keys.lock().unwrap().shift_remove_full(key) {
+                        Some((index, _)) => LoopChange::Remove(index),
+                        None => return,
+                    },
+                    Change::Replaced => {
+                        *keys.lock().unwrap() = map.keys_signal().untracked_with(IndexSet::clone);
+
+                        LoopChange::Reset
+                    }
+                };
+
+                f(change);
+            })
+    }
+
+    fn link(source: &ReactiveMap<K, V>, target: &ReactiveMap<K, V>) {
+        source.link_to(target);
+    }
+}
+
+fn make_map_item<K, V>(map: &ReactiveMap<K, V>, key: &K) -> Option<LoopItem<V>>
+where
+    K: Clone + Eq + Hash + ToString + 'static,
+    V: Clone + PartialEq + 'static, {
+    let signal = map.get_signal(key)?;
+    let value = signal.runtime().create_memo(move |_| Some(signal.get()));
+
+    Some(LoopItem {
+        key: key.to_string(),
+        value,
+    })
+}
+
+fn make_vec_item<T: Clone + PartialEq + 'static>(item: &Signal<T>) -> LoopItem<T> {
+    let value = {
+        let item = item.clone();
+
+        item.runtime().create_memo(move |_| Some(item.get()))
+    };
+
+    LoopItem {
+        key: item.id(),
+        value,
+    }
+}
+
+// no-coverage:start
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signal::tests::create_runtime;
+    use std::{cell::RefCell, rc::Rc};
+
+    fn item_keys<C: LoopCollection>(source: &C::Source) -> Vec<String> {
+        C::make_items(source).into_iter().map(|item| item.key).collect()
+    }
+
+    /// Keys of the elements of a loop over the source, kept up to date by applying the watched changes
+    fn watch_keys<C: LoopCollection>(source: &C::Source) -> (Rc<RefCell<Vec<String>>>, Subscription) {
+        let keys = Rc::new(RefCell::new(item_keys::<C>(source)));
+        let subscription = {
+            let keys = Rc::clone(&keys);
+            let source = source.clone();
+
+            C::watch(&source.clone(), move |change| {
+                change.apply(&mut keys.borrow_mut(), |item| item.key, || item_keys::<C>(&source));
+            })
+        };
+
+        (keys, subscription)
+    }
+
+    #[test]
+    fn test_signal_vec_changes() {
+        let rt = create_runtime();
+        let vec = Arc::clone(&rt).create_signal_vec(vec!["a", "b", "c"]);
+        let (keys, _subscription) = watch_keys::<SignalVec<&str>>(&vec);
+        let initial = keys.borrow().clone();
+
+        vec.push("d");
+        vec.insert(0, "e").unwrap();
+        vec.remove(2);
+        assert!(vec.move_item(0, 3), "item should be moved");
+        assert!(vec.swap(0, 1), "items should be swapped");
+
+        assert_eq!(
+            *keys.borrow(),
+            item_keys::<SignalVec<&str>>(&vec),
+            "the elements should follow the order of the vector"
+        );
+        assert_eq!(
+            vec.to_vec(),
+            vec!["c", "a", "d", "e"],
+            "vector should hold the moved values"
+        );
+        assert_eq!(
+            keys.borrow()[..2],
+            [initial[2].clone(), initial[0].clone()],
+            "untouched items should keep their keys"
+        );
+
+        vec.clear();
+        assert!(keys.borrow().is_empty(), "the elements should be cleared");
+    }
+
+    #[test]
+    fn test_signal_vec_relink() {
+        let rt = create_runtime();
+        let vec = Arc::clone(&rt).create_signal_vec(vec!["a", "b"]);
+        let (_, subscription) = watch_keys::<SignalVec<&str>>(&vec);
+
+        // as the component does, the new collection is linked, and the elements are rebuilt by watching it again
+        let other = Arc::clone(&rt).create_signal_vec(vec!["c", "d", "e"]);
+        <SignalVec<&str> as LoopCollection>::link(&other, &vec);
+        drop(subscription);
+
+        let (keys, _subscription) = watch_keys::<SignalVec<&str>>(&vec);
+
+        assert_eq!(
+            vec.to_vec(),
+            vec!["c", "d", "e"],
+            "linked vector should hold the new items"
+        );
+
+        other.remove(0);
+        vec.push("f");
+
+        assert_eq!(
+            *keys.borrow(),
+            item_keys::<SignalVec<&str>>(&vec),
+            "the elements should follow the changes of the linked vector"
+        );
+        assert_eq!(
+            other.to_vec(),
+            vec!["d", "e", "f"],
+            "both vectors should share their items"
+        );
+    }
+
+    #[test]
+    fn test_reactive_map_changes() {
+        let rt = create_runtime();
+        let map = Arc::clone(&rt).create_reactive_map([(1, "a"), (2, "b"), (3, "c")]);
+        let (keys, _subscription) = watch_keys::<ReactiveMap<u32, &str>>(&map);
+
+        map.remove(&2);
+        map.insert(4, "d");
+        map.insert(1, "e");
+
+        assert_eq!(
+            *keys.borrow(),
+            vec!["1", "3", "4"],
+            "the elements should follow the insertion order of the map"
+        );
+        assert_eq!(
+            *keys.borrow(),
+            item_keys::<ReactiveMap<u32, &str>>(&map),
+            "the elements should match the items of the map"
+        );
+    }
+}
+// no-coverage:stop
@@ -1,40 +1,47 @@
-use super::element::LoopElement;
-use crate::signal::{KeyedCollection, Signal};
+use super::{
+    collection::{LoopChange, LoopCollection, LoopItem},
+    element::LoopElement,
+};
+use crate::signal::Subscription;
 use yew::{Component, Context, Html, Properties, Children, html};
 use std::marker::PhantomData;
 
-pub enum Msg {
+pub enum Msg<T: 'static> {
     Update,
+    Change(LoopChange<T>),
 }
 
 #[derive(Properties)]
-pub struct Props<C: KeyedCollection> {
-    pub values:   Signal<C>,
+pub struct Props<C: LoopCollection> {
+    pub values:   C::Source,
     pub children: Children,
 
     #[cfg(feature = "loop_duration")]
     #[prop_or_default]
-    pub duration: Option<Signal<crate::duration::DurationInfo>>,
+    pub duration: Option<crate::signal::Signal<crate::duration::DurationInfo>>,
 }
 
-impl<C: KeyedCollection> PartialEq for Props<C> {
+impl<C: LoopCollection> PartialEq for Props<C> {
     fn eq(&self, other: &Self) -> bool {
         self.values == other.values
     }
 }
 
-impl<C: KeyedCollection> Eq for Props<C> {}
+impl<C: LoopCollection> Eq for Props<C> {}
 
-pub struct For<T: Clone + PartialEq + Default + 'static, C: KeyedCollection> {
-    collection: Signal<C>,
+pub struct For<T: Clone + PartialEq + Default + 'static, C: LoopCollection> {
+    collection: C::Source,
     values:     Vec<Html>,
+    changes:    Option<Subscription>,
     t:          PhantomData<T>,
 }
 
-impl<T: Default + Clone + PartialEq + 'static, C: KeyedCollection<Value = T>> For<T, C> {
-    fn make_item(children: &Children, values: Signal<C>, key: &str) -> Html {
-        let value = values.runtime().create_keyed_signal(values, key);
-        let key = key.to_string();
+impl<T: Default + Clone + PartialEq + 'static, C: LoopCollection<Value = T>> For<T, C> {
+    fn make_item(children: &Children, item: LoopItem<T>) -> Html {
+        let LoopItem {
+            key,
+            value,
+        } = item;
 
         html! {
             <LoopElement<T> {key} {value}>
@@ -43,38 +50,44 @@ impl<T: Default + Clone + PartialEq + 'static, C: KeyedCollection<Value = T>> Fo
         }
     }
 
-    fn make_values(children: &Children, values: Signal<C>) -> Vec<Html> {
-        let list = values.clone();
+    fn make_values(children: &Children, values: &C::Source) -> Vec<Html> {
+        C::make_items(values)
+            .into_iter()
+            .map(|item| Self::make_item(children, item))
+            .collect()
+    }
+
+    fn watch(ctx: &Context<Self>, collection: &C::Source) -> Subscription {
+        let scope = ctx.link().clone();
+
+        C::watch(collection, move |change| scope.send_message(Msg::Change(change)))
+    }
+
+    fn apply_change(&mut self, children: &Children, change: LoopChange<T>) {
+        let collection = &self.collection;
 
-        values.with(|c| {
-            c.iter_keys()
-                .map(|k| Self::make_item(children, list.clone(), &k))
-                .collect::<Vec<_>>()
-        })
+        change.apply(
+            &mut self.values,
+            |item| Self::make_item(children, item),
+            || Self::make_values(children, collection),
+        );
     }
 }
 
-impl<T: Default + Clone + PartialEq + 'static, C: KeyedCollection<Value = T>> Component for For<T, C> {
-    type Message = Msg;
+impl<T: Default + Clone + PartialEq + 'static, C: LoopCollection<Value = T>> Component for For<T, C> {
+    type Message = Msg<T>;
     type Properties = Props<C>;
 
     fn create(ctx: &Context<Self>) -> Self {
         let collection = ctx.props().values.clone();
-        let values = Self::make_values(&ctx.props().children, collection.clone());
-
-        {
-            let scope = ctx.link().clone();
-            let values = collection.clone();
+        let values = Self::make_values(&ctx.props().children, &collection);
 
-            values.runtime().create_effect(move || {
-                values.with(|_| ());
-                scope.send_message(Msg::Update);
-            });
-        }
+        let changes = Self::watch(ctx, &collection);
 
         Self {
             values,
             collection,
+            changes: Some(changes),
             t: PhantomData,
         }
     }
@@ -82,7 +95,12 @@ impl<T: Default + Clone + PartialEq + 'static, C: KeyedCollection<Value = T>> Co
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
         match msg {
             Msg::Update => {
-                self.values = Self::make_values(&ctx.props().children, self.collection.clone());
+                self.values = Self::make_values(&ctx.props().children, &self.collection);
+
+                true
+            }
+            Msg::Change(change) => {
+                self.apply_change(&ctx.props().children, change);
 
                 true
             }
@@ -90,8 +108,14 @@ impl<T: Default + Clone + PartialEq + 'static, C: KeyedCollection<Value = T>> Co
     }
 
     fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        // the linked collection holds other items, which are rebuilt as no diff is emitted for them,
+        // and watched again, as the watch can hold a state of the previous items
         if ctx.props().values != old_props.values {
-            ctx.props().values.link_to(&self.collection);
+            C::link(&ctx.props().values, &self.collection);
+            self.changes = Some(Self::watch(ctx, &self.collection));
+            self.apply_change(&ctx.props().children, LoopChange::Reset);
+
+            return true;
         }
 
         if ctx.props().children != old_props.children {
@@ -114,6 +138,11 @@ impl<T: Default + Clone + PartialEq + 'static, C: KeyedCollection<Value = T>> Co
         }
     }
 
+    fn destroy(&mut self, _ctx: &Context<Self>) {
+        // the changes are not watched anymore once unmounted
+        self.changes.take();
+    }
+
     #[cfg(feature = "loop_duration")]
     fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
         if let Some(duration) = ctx.props().duration.as_ref().cloned() {
@@ -123,3 +152,107 @@ impl<T: Default + Clone + PartialEq + 'static, C: KeyedCollection<Value = T>> Co
         }
     }
 }
+
+// no-coverage:start
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+    use crate::{
+        components::{LoopContext, Reactor},
+        hooks::use_reactor,
+        signal::{ReactiveMap, SignalVec},
+    };
+    use yew::{function_component, use_state, LocalServerRenderer};
+    use futures::executor::block_on;
+
+    struct Name;
+
+    impl Component for Name {
+        type Message = ();
+        type Properties = ();
+
+        fn create(_ctx: &Context<Self>) -> Self {
+            Self
+        }
+
+        fn view(&self, ctx: &Context<Self>) -> Html {
+            html! { <i>{ctx.get_loop_var::<String>().get_value()}</i> }
+        }
+    }
+
+    #[function_component]
+    fn List() -> Html {
+        let rt = use_reactor();
+        let values = use_state(move || {
+            let values = rt.create_signal_vec(vec!["b".to_string(), "c".to_string()]);
+
+            values.insert(0, "a".to_string()).unwrap();
+            values.replace(2, "d".to_string());
+            values
+        });
+
+        html! {
+            <For<String, SignalVec<String>> values={(*values).clone()}>
+                <Name />
+            </For<String, SignalVec<String>>>
+        }
+    }
+
+    #[function_component]
+    fn App() -> Html {
+        html! {
+            <Reactor>
+                <List />
+            </Reactor>
+        }
+    }
+
+    #[function_component]
+    fn MapList() -> Html {
+        let rt = use_reactor();
+        let values = use_state(move || {
+            let values = rt.create_reactive_map([(1, "a".to_string()), (2, "b".to_string())]);
+
+            values.insert(3, "c".to_string());
+            values.remove(&1);
+            values.update(&2, |v| v.push('!'));
+            values
+        });
+
+        html! {
+            <For<String, ReactiveMap<u32, String>> values={(*values).clone()}>
+                <Name />
+            </For<String, ReactiveMap<u32, String>>>
+        }
+    }
+
+    #[function_component]
+    fn MapApp() -> Html {
+        html! {
+            <Reactor>
+                <MapList />
+            </Reactor>
+        }
+    }
+
+    #[test]
+    fn test_render_signal_vec() {
+        let html = block_on(LocalServerRenderer::<App>::new().hydratable(false).render());
+
+        assert_eq!(
+            html, "<i>a</i><i>b</i><i>d</i>",
+            "each item of the vector should be rendered in order"
+        );
+    }
+
+    #[test]
+    fn test_render_reactive_map() {
+        let html = block_on(LocalServerRenderer::<MapApp>::new().hydratable(false).render());
+
+        assert_eq!(
+            html, "<i>b!</i><i>c</i>",
+            "each value of the map should be rendered in insertion order"
+        );
+    }
+}
+// no-coverage:stop
@@ -1,3 +1,4 @@
+mod collection;
 mod component;
 mod context;
 mod element;
@@ -6,5 +7,6 @@ pub(crate) use context::LoopDataContext;
 
 pub use self::{
     component::For,
+    collection::{LoopChange, LoopCollection, LoopItem},
     context::{LoopContext, LoopVar},
 };
@@ -1,4 +1,5 @@
 mod condition;
+mod store;
 mod values;
 
 pub(crate) mod r#loop;
@@ -7,6 +8,7 @@ pub(crate) mod reactor;
 pub use self::{
     condition::{IfTrue, IfFalse, AsBool},
     reactor::{Reactor, ReactorContext},
+    store::StoreProvider,
     values::{Item, Value, LoopValue},
-    r#loop::{For, LoopContext},
+    r#loop::{For, LoopChange, LoopCollection, LoopContext, LoopItem},
 };
@@ -3,6 +3,12 @@ use crate::{signal::Runtime, defer::DeferRunner, spawner::SpawnGenerator};
 use yew::{context::ContextProvider, Children, Component, Context, Html, Properties, ToHtml, html};
 use std::sync::Arc;
 
+#[cfg(feature = "hydration")]
+use crate::signal::StateSnapshot;
+
+#[cfg(all(feature = "ssr", not(target_arch = "wasm32")))]
+use crate::{defer::SyncRunner, spawner::generators::SyncSpawner};
+
 #[derive(Properties, Default)]
 pub struct Props {
     #[prop_or_default]
@@ -55,6 +61,22 @@ impl Component for Reactor {
     fn create(ctx: &Context<Self>) -> Self {
         let mut rt = Runtime::new();
 
+        // signals are created in the same order on the server and on the client
+        #[cfg(any(feature = "ssr", feature = "hydration"))]
+        {
+            rt = rt.with_deterministic_ids();
+        }
+
+        // while rendering on the server, there is no event loop to run deferred jobs and futures later,
+        // and the children are destroyed before the state is prepared
+        #[cfg(all(feature = "ssr", not(target_arch = "wasm32")))]
+        {
+            rt = rt
+                .with_defer_runner(SyncRunner::new())
+                .with_spawn_generator(SyncSpawner::new())
+                .with_retained_states();
+        }
+
         if let Some(runner) = ctx.props().with_defer_runner.as_ref() {
             rt = rt.with_defer_runner(Arc::clone(runner));
         }
@@ -63,6 +85,15 @@ impl Component for Reactor {
             rt = rt.with_spawn_generator(Arc::clone(generator));
         }
 
+        // the state rendered by the server is applied to the signals as soon as the children register them
+        #[cfg(feature = "hydration")]
+        if let Some(snapshot) = ctx
+            .prepared_state()
+            .and_then(|state| StateSnapshot::from_json(state).ok())
+        {
+            rt.hydrate_state(snapshot);
+        }
+
         Self {
             rt,
         }
@@ -100,4 +131,95 @@ impl Component for Reactor {
             </ContextProvider<ReactorDataContext>>
         }
     }
+
+    #[cfg(feature = "ssr")]
+    fn prepare_state(&self) -> Option<String> {
+        let (snapshot, errors) = self.rt.take_partial_snapshot();
+
+        // an unserializable state is left out, and is not hydrated on the client
+        for (key, err) in errors {
+            eprintln!("Unserializable state {key:?}: {err}");
+        }
+
+        if snapshot.is_empty() {
+            return None;
+        }
+
+        // the state is written as is into a script element, which must not be closed by a value
+        snapshot.to_json().ok().map(|json| json.replace('<', "\\u003c"))
+    }
+
+    fn destroy(&mut self, _ctx: &Context<Self>) {
+        self.rt.dispose();
+    }
+}
+
+// no-coverage:start
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+    use crate::hooks::use_signal;
+    use yew::{function_component, LocalServerRenderer};
+    use futures::executor::block_on;
+    use std::collections::HashMap;
+
+    #[function_component]
+    fn Counter() -> Html {
+        let count = use_signal(41);
+
+        count.runtime().register_state("count", &count);
+        count.update(|v| *v += 1);
+
+        html! { <span>{count.get()}</span> }
+    }
+
+    #[function_component]
+    fn Pairs() -> Html {
+        // a map with non-string keys cannot be serialized to JSON
+        let pairs = use_signal(HashMap::from([((1, 2), 3)]));
+
+        pairs.runtime().register_state("pairs", &pairs);
+
+        html! { <span>{pairs.with(HashMap::len)}</span> }
+    }
+
+    #[function_component]
+    fn App() -> Html {
+        html! {
+            <Reactor>
+                <Counter />
+            </Reactor>
+        }
+    }
+
+    #[function_component]
+    fn PartialApp() -> Html {
+        html! {
+            <Reactor>
+                <Counter />
+                <Pairs />
+            </Reactor>
+        }
+    }
+
+    #[test]
+    fn test_render_state() {
+        let html = block_on(LocalServerRenderer::<App>::new().hydratable(false).render());
+
+        assert_eq!(
+            html, r#"<span>42</span><script type="application/x-yew-comp-state">{"count":42}</script>"#,
+            "registered state should be rendered after the children"
+        );
+    }
+
+    #[test]
+    fn test_render_partial_state() {
+        let html = block_on(LocalServerRenderer::<PartialApp>::new().hydratable(false).render());
+
+        assert_eq!(
+            html, r#"<span>42</span><span>1</span><script type="application/x-yew-comp-state">{"count":42}</script>"#,
+            "unserializable state should be left out of the rendered state"
+        );
+    }
 }
+// no-coverage:stop
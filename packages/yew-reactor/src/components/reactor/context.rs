@@ -1,7 +1,8 @@
 use super::Reactor;
-use crate::signal::Runtime;
+use crate::signal::{Runtime, StoreScope};
 use yew::{
     html::{Scope, AnyScope},
+    Callback,
     Component,
     Context,
 };
@@ -9,12 +10,14 @@ use std::sync::Arc;
 
 #[derive(Clone, PartialEq, Eq)]
 pub(crate) struct ReactorDataContext {
-    rt: Arc<Runtime>,
+    rt:     Arc<Runtime>,
+    stores: StoreScope,
 }
 
 impl ReactorDataContext {
     pub(super) fn new(rt: Arc<Runtime>) -> Self {
         Self {
+            stores: rt.stores(),
             rt,
         }
     }
@@ -22,23 +25,51 @@ impl ReactorDataContext {
     pub(crate) fn runtime(&self) -> Arc<Runtime> {
         Arc::clone(&self.rt)
     }
+
+    pub(crate) fn stores(&self) -> StoreScope {
+        self.stores.clone()
+    }
+
+    /// Context of a nested level of the component tree, with its own store scope
+    pub(crate) fn child(&self) -> Self {
+        Self {
+            rt:     Arc::clone(&self.rt),
+            stores: self.stores.child(),
+        }
+    }
 }
 
 /// Component extension trait for Reactor component
 pub trait ReactorContext {
     fn runtime(&self) -> Option<Arc<Runtime>>;
+
+    /// Store scope of the nearest `StoreProvider`, or of the reactor
+    fn store_scope(&self) -> Option<StoreScope>;
+
+    fn store<T: Clone + 'static>(&self) -> Option<T> {
+        self.store_scope()?.get::<T>()
+    }
 }
 
 impl<C: Component> ReactorContext for Context<C> {
     fn runtime(&self) -> Option<Arc<Runtime>> {
         self.link().runtime()
     }
+
+    fn store_scope(&self) -> Option<StoreScope> {
+        self.link().store_scope()
+    }
 }
 
 impl<C: Component> ReactorContext for Scope<C> {
     fn runtime(&self) -> Option<Arc<Runtime>> {
         get_runtime(self.get_parent()?)
     }
+
+    fn store_scope(&self) -> Option<StoreScope> {
+        self.context::<ReactorDataContext>(Callback::noop())
+            .map(|(context, _)| context.stores())
+    }
 }
 
 fn get_runtime(scope: &AnyScope) -> Option<Arc<Runtime>> {
@@ -0,0 +1,117 @@
+use crate::components::reactor::ReactorDataContext;
+use yew::{context::ContextProvider, Callback, Children, Component, Context, Html, Properties, ToHtml, html};
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub children: Children,
+}
+
+/// Gives its children their own store scope, which falls back to the stores of the parents,
+/// the values provided in this scope are dropped with the component
+pub struct StoreProvider {
+    context: Option<ReactorDataContext>,
+}
+
+impl Component for StoreProvider {
+    type Message = ();
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let context = ctx
+            .link()
+            .context::<ReactorDataContext>(Callback::noop())
+            .map(|(parent, _)| parent.child());
+
+        Self {
+            context,
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let children = ctx.props().children.to_html();
+
+        match self.context.clone() {
+            Some(context) => html! {
+                <ContextProvider<ReactorDataContext> {context}>
+                    {children}
+                </ContextProvider<ReactorDataContext>>
+            },
+            None => children,
+        }
+    }
+}
+
+// no-coverage:start
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+    use crate::{
+        components::Reactor,
+        hooks::{use_store, use_store_scope},
+    };
+    use yew::{function_component, use_state, LocalServerRenderer};
+    use futures::executor::block_on;
+
+    #[derive(Clone, Properties, PartialEq)]
+    struct ThemeProps {
+        provide: Option<&'static str>,
+    }
+
+    #[function_component]
+    fn Theme(props: &ThemeProps) -> Html {
+        let stores = use_store_scope().expect("Theme should be rendered inside a reactor");
+
+        // the value is provided once, when the component is created
+        use_state(|| {
+            if let Some(theme) = props.provide {
+                stores.provide(theme);
+            }
+        });
+
+        let theme = use_store::<&str>().unwrap_or("none");
+
+        html! { <span>{theme}</span> }
+    }
+
+    #[function_component]
+    fn App() -> Html {
+        html! {
+            <Reactor>
+                <Theme provide={Some("light")} />
+                <StoreProvider>
+                    <Theme provide={Some("dark")} />
+                    <Theme provide={None} />
+                </StoreProvider>
+                <Theme provide={None} />
+            </Reactor>
+        }
+    }
+
+    #[function_component]
+    fn Outside() -> Html {
+        let scope = use_store_scope();
+
+        html! { <span>{scope.is_some()}</span> }
+    }
+
+    #[test]
+    fn test_store_scope_outside_of_reactor() {
+        let html = block_on(LocalServerRenderer::<Outside>::new().hydratable(false).render());
+
+        assert_eq!(
+            html, "<span>false</span>",
+            "no store scope should be found outside of a reactor"
+        );
+    }
+
+    #[test]
+    fn test_render_store_scopes() {
+        let html = block_on(LocalServerRenderer::<App>::new().hydratable(false).render());
+
+        assert_eq!(
+            html, "<span>light</span><span>dark</span><span>dark</span><span>light</span>",
+            "each provider level should shadow the stores of its parents"
+        );
+    }
+}
+// no-coverage:stop
@@ -1,9 +1,13 @@
 use crate::signal::{Runtime, Signal};
 use itertools::Itertools;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
 use std::{
     fmt::{Debug, Formatter, Result as FmtResult},
     collections::HashSet,
-    sync::{Arc, Mutex},
+    sync::Arc,
 };
 
 #[derive(Clone, PartialEq, Eq)]
@@ -112,17 +116,13 @@ impl CssClasses {
 
     pub fn register_class_signal(&self, signal: Signal<String>) {
         let classes = self.clone();
-        let old = Mutex::new(signal.get());
 
-        self.runtime().create_effect(move || {
-            let mut old = old.lock().unwrap();
-            let old_value = old.clone();
-            let new_value = signal.with(|v| {
-                *old = v.clone();
-                old.as_str()
-            });
+        self.runtime().create_effect_with_prev(move |old: Option<String>| {
+            let new = signal.get();
+
+            classes.replace(old.as_deref().unwrap_or_default(), &new);
 
-            classes.replace(&old_value, new_value);
+            new
         });
     }
 
@@ -137,6 +137,26 @@ impl Debug for CssClasses {
     }
 }
 
+#[cfg(feature = "serde")]
+impl Serialize for CssClasses {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.values
+            .with(|values| serializer.collect_seq(values.iter().sorted()))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for CssClasses {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let runtime = crate::signal::current_runtime_for::<D>("CSS classes")?;
+        let classes = Vec::<String>::deserialize(deserializer)?;
+
+        Ok(Self {
+            values: runtime.create_signal(classes.into_iter().collect()),
+        })
+    }
+}
+
 // no-coverage:start
 #[cfg(test)]
 mod tests {
@@ -314,6 +334,24 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_css_classes() {
+        let (classes, list) = make_css();
+        let json = serde_json::to_string(&classes).unwrap();
+
+        assert_eq!(
+            json, r#"["class1","class2","class3"]"#,
+            "CSS classes should be serialized as a sorted list"
+        );
+
+        let rt = classes.runtime();
+        let copy: CssClasses = rt.with_current(|| serde_json::from_str(&json)).unwrap();
+
+        assert_eq!(copy.get(), list, "CSS classes should be deserialized from the list");
+        assert_ne!(copy, classes, "deserialized CSS classes should be a new instance");
+    }
+
     #[test]
     fn test_link_css_classes() {
         let (src, _) = make_css();
@@ -4,3 +4,6 @@ mod runner;
 pub(crate) mod runners;
 
 pub use self::{runner::DeferRunner, manager::DeferManager};
+
+#[cfg(feature = "ssr")]
+pub use self::runners::SyncRunner;
@@ -1,9 +1,15 @@
 mod default;
 
+#[cfg(feature = "ssr")]
+mod sync;
+
 #[cfg(test)]
 mod test;
 
 pub(super) use default::DefaultRunner;
 
+#[cfg(feature = "ssr")]
+pub use sync::SyncRunner;
+
 #[cfg(test)]
 pub(crate) use test::RunnerForTests;
@@ -0,0 +1,41 @@
+use crate::defer::DeferRunner;
+use std::sync::Arc;
+
+/// Runner which runs the deferred jobs immediately, suitable for server-side rendering
+#[derive(Clone, Debug, Default)]
+pub struct SyncRunner;
+
+impl SyncRunner {
+    pub fn new() -> Self {
+        SyncRunner
+    }
+}
+
+impl DeferRunner for SyncRunner {
+    fn run(&self, f: Arc<dyn Fn()>) {
+        f();
+    }
+}
+
+// no-coverage:start
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_run() {
+        let value = Arc::new(AtomicUsize::new(0));
+
+        {
+            let value = Arc::clone(&value);
+
+            SyncRunner::new().run(Arc::new(move || {
+                value.fetch_add(1, Ordering::Relaxed);
+            }));
+        }
+
+        assert_eq!(value.load(Ordering::Relaxed), 1, "job should be run immediately");
+    }
+}
+// no-coverage:stop
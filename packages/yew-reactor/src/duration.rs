@@ -1,4 +1,8 @@
 use instant::{Duration, Instant};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
 use std::{
     fmt::{Display, Formatter, Result as FmtResult},
     cell::RefCell,
@@ -46,3 +50,43 @@ impl Display for DurationInfo {
         write!(f, "{ms}{} ms", ns_str.trim_end_matches('0'))
     }
 }
+
+#[cfg(feature = "serde")]
+impl Serialize for DurationInfo {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.duration.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for DurationInfo {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self {
+            start:    RefCell::new(Instant::now()),
+            duration: Duration::deserialize(deserializer)?,
+        })
+    }
+}
+
+// no-coverage:start
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_duration_info() {
+        let mut info = DurationInfo::new();
+
+        info.duration = Duration::from_millis(1500);
+
+        let json = serde_json::to_string(&info).unwrap();
+        let copy: DurationInfo = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(
+            json, r#"{"secs":1,"nanos":500000000}"#,
+            "duration info should be serialized as a duration"
+        );
+        assert_eq!(copy.duration(), info.duration(), "duration should be restored");
+    }
+}
+// no-coverage:stop
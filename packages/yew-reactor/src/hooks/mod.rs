@@ -1,5 +1,10 @@
 mod loop_var;
 mod reactor;
 mod signal;
+mod store;
 
-pub use self::{signal::use_signal, reactor::use_reactor};
+pub use self::{
+    signal::use_signal,
+    reactor::use_reactor,
+    store::{use_store, use_store_scope},
+};
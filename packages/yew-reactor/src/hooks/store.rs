@@ -0,0 +1,15 @@
+use crate::{signal::StoreScope, components::reactor::ReactorDataContext};
+use yew::{hook, use_context};
+
+/// Store scope of the nearest `StoreProvider`, or of the reactor, `None` outside of a reactor
+#[hook]
+pub fn use_store_scope() -> Option<StoreScope> {
+    use_context::<ReactorDataContext>().map(|context| context.stores())
+}
+
+#[hook]
+pub fn use_store<T>() -> Option<T>
+where
+    T: Clone + 'static, {
+    use_store_scope()?.get::<T>()
+}
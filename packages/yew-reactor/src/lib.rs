@@ -1,5 +1,9 @@
+// lets the code generated by the derive macros refer to this crate as `yew_reactor`, even inside it
+extern crate self as yew_reactor;
+
 mod backtrace;
 mod id_generator;
+mod macros;
 
 pub mod action;
 pub mod components;
@@ -7,6 +11,7 @@ pub mod css;
 pub mod defer;
 pub mod hooks;
 pub mod signal;
+pub mod signal_struct;
 pub mod spawner;
 
 #[cfg(feature = "loop_duration")]
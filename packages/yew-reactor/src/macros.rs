@@ -0,0 +1,119 @@
+/// Clones the listed values before evaluating the expression, usually a `move` closure
+///
+/// `clone!([a, b = expr] move || ...)` clones `a`, and binds a clone of `expr` to `b`
+#[macro_export]
+macro_rules! clone {
+    ([$($name:ident $(= $value:expr)?),* $(,)?] $body:expr) => {{
+        $(let $name = $crate::clone!(@value $name $(= $value)?);)*
+        $body
+    }};
+    (@value $name:ident) => {
+        $name.clone()
+    };
+    (@value $name:ident = $value:expr) => {
+        ($value).clone()
+    };
+}
+
+/// Creates an effect capturing clones of the listed values,
+/// the runtime is inferred from the first value when it is not given
+#[macro_export]
+macro_rules! effect {
+    ([$first:ident $(= $first_value:expr)? $(, $name:ident $(= $value:expr)?)* $(,)?], $body:expr) => {
+        $crate::clone!([$first $(= $first_value)? $(, $name $(= $value)?)*] {
+            $first.runtime().create_effect(move || {
+                $body;
+            })
+        })
+    };
+    ($rt:expr, [$($name:ident $(= $value:expr)?),* $(,)?], $body:expr) => {
+        $crate::clone!([$($name $(= $value)?),*] {
+            $rt.create_effect(move || {
+                $body;
+            })
+        })
+    };
+}
+
+/// Creates a memo capturing clones of the listed values,
+/// the runtime is inferred from the first value when it is not given
+#[macro_export]
+macro_rules! memo {
+    (
+        [$first:ident $(= $first_value:expr)? $(, $name:ident $(= $value:expr)?)* $(,)?],
+        |$prev:tt $(: $ty:ty)?| $body:expr
+    ) => {
+        $crate::clone!([$first $(= $first_value)? $(, $name $(= $value)?)*] {
+            $first.runtime().create_memo(move |$prev $(: $ty)?| $body)
+        })
+    };
+    ($rt:expr, [$($name:ident $(= $value:expr)?),* $(,)?], |$prev:tt $(: $ty:ty)?| $body:expr) => {
+        $crate::clone!([$($name $(= $value)?),*] {
+            ::std::sync::Arc::clone(&$rt).create_memo(move |$prev $(: $ty)?| $body)
+        })
+    };
+}
+
+// no-coverage:start
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use crate::signal::tests::create_runtime;
+
+    #[test]
+    fn test_clone() {
+        let rt = create_runtime();
+        let a = Arc::clone(&rt).create_signal(1);
+        let b = rt.create_signal(2);
+
+        let sum = clone!([a, b] move || a.get() + b.get());
+        let scaled = clone!([a, factor = b] move || a.get() * factor.get());
+
+        a.set(3);
+
+        assert_eq!(sum(), 5, "cloned signals should be read by the closure");
+        assert_eq!(scaled(), 6, "renamed clone should be read by the closure");
+        assert_eq!(b.get(), 2, "original signals should still be usable");
+    }
+
+    #[test]
+    fn test_effect() {
+        let rt = create_runtime();
+        let a = Arc::clone(&rt).create_signal(1);
+        let b = Arc::clone(&rt).create_signal(2);
+        let sum = Arc::clone(&rt).create_signal(0);
+        let last = Arc::clone(&rt).create_signal(0);
+
+        effect!([sum, a, b], {
+            sum.set(a.get() + b.get());
+        });
+        effect!(rt, [last, value = a], last.set(value.get()));
+
+        assert_eq!(sum.get(), 3, "effect should run immediately");
+        assert_eq!(last.get(), 1, "effect with explicit runtime should run immediately");
+
+        a.set(5);
+
+        assert_eq!(sum.get(), 7, "effect should re-run when a captured signal changes");
+        assert_eq!(last.get(), 5, "effect with explicit runtime should re-run");
+    }
+
+    #[test]
+    fn test_memo() {
+        let rt = create_runtime();
+        let a = Arc::clone(&rt).create_signal(2);
+
+        let doubled = memo!([a], |_| a.get() * 2);
+        let total = memo!(rt, [a], |prev: Option<&i32>| prev.copied().unwrap_or_default()
+            + a.get());
+
+        assert_eq!(doubled.get(), 4, "memo should be computed");
+        assert_eq!(total.get(), 2, "memo with explicit runtime should be computed");
+
+        a.set(3);
+
+        assert_eq!(doubled.get(), 6, "memo should follow the captured signal");
+        assert_eq!(total.get(), 5, "memo should receive the previous value");
+    }
+}
+// no-coverage:stop
@@ -0,0 +1,175 @@
+use super::Runtime;
+use crate::spawner::LocalFuture;
+use futures::{
+    future::{abortable, AbortHandle},
+    task::noop_waker_ref,
+};
+use std::{
+    future::Future,
+    panic::AssertUnwindSafe,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::Context,
+};
+
+/// Future of the last run of an async effect or memo, aborted when superseded or when the effect is dropped
+#[derive(Default)]
+pub(super) struct InFlight(Mutex<Option<AbortHandle>>);
+
+impl InFlight {
+    pub(super) fn replace(&self, handle: Option<AbortHandle>) {
+        if let Some(previous) = std::mem::replace(&mut *self.0.lock().unwrap(), handle) {
+            previous.abort();
+        }
+    }
+}
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        self.replace(None);
+    }
+}
+
+pub(super) fn create_async_effect<R, F>(runtime: &Arc<Runtime>, f: F)
+where
+    R: Future<Output = ()> + 'static,
+    F: Fn() -> R + 'static, {
+    let weak_runtime = Arc::downgrade(runtime);
+    let in_flight = InFlight::default();
+
+    runtime.create_effect(move || {
+        let Some(runtime) = weak_runtime.upgrade() else {
+            return;
+        };
+
+        // a new run supersedes the previous one, even if it completes synchronously
+        in_flight.replace(None);
+
+        let mut fut = Box::pin(f());
+
+        // the first poll runs inside the effect, so the signals read before the first await are tracked
+        let mut cx = Context::from_waker(noop_waker_ref());
+
+        if Pin::new(&mut fut).poll(&mut cx).is_pending() {
+            let (task, handle) = abortable(fut);
+
+            in_flight.replace(Some(handle));
+            runtime.spawn(LocalFuture::new(AssertUnwindSafe(async move {
+                let _ = task.await;
+            })));
+        }
+    });
+}
+
+// no-coverage:start
+#[cfg(test)]
+mod tests {
+    use crate::signal::tests::create_queued_runtime;
+    use futures::channel::oneshot;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_async_effect_aborts_superseded_runs() {
+        let (rt, spawner) = create_queued_runtime();
+        let query = Arc::clone(&rt).create_signal(1);
+        let completed = Arc::new(Mutex::new(vec![]));
+        let senders = Arc::new(Mutex::new(vec![]));
+
+        {
+            let query = query.clone();
+            let completed = Arc::clone(&completed);
+            let senders = Arc::clone(&senders);
+
+            rt.create_async_effect(move || {
+                let query = query.get();
+                let completed = Arc::clone(&completed);
+                let (tx, rx) = oneshot::channel::<()>();
+
+                senders.lock().unwrap().push(tx);
+
+                async move {
+                    let _ = rx.await;
+
+                    completed.lock().unwrap().push(query);
+                }
+            });
+        }
+
+        query.set(2);
+
+        for tx in senders.lock().unwrap().drain(..) {
+            let _ = tx.send(());
+        }
+        spawner.run_all();
+
+        assert_eq!(*completed.lock().unwrap(), vec![2], "superseded run should be aborted");
+    }
+
+    #[test]
+    fn test_async_effect_sync_completion() {
+        let (rt, _) = create_queued_runtime();
+        let value = Arc::clone(&rt).create_signal(1);
+        let seen = Arc::new(Mutex::new(vec![]));
+
+        {
+            let value = value.clone();
+            let seen = Arc::clone(&seen);
+
+            rt.create_async_effect(move || {
+                let value = value.get();
+                let seen = Arc::clone(&seen);
+
+                async move { seen.lock().unwrap().push(value) }
+            });
+        }
+
+        value.set(2);
+
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![1, 2],
+            "effect completing without awaiting should run immediately"
+        );
+    }
+
+    #[test]
+    fn test_async_effect_aborted_with_owner() {
+        let (rt, spawner) = create_queued_runtime();
+        let source = Arc::clone(&rt).create_signal(());
+        let completed = Arc::new(Mutex::new(false));
+        let sender = Arc::new(Mutex::new(None));
+
+        {
+            let source = source.clone();
+            let completed = Arc::clone(&completed);
+            let sender = Arc::clone(&sender);
+
+            rt.create_async_effect(move || {
+                source.with(|_| ());
+
+                let completed = Arc::clone(&completed);
+                let (tx, rx) = oneshot::channel::<()>();
+
+                *sender.lock().unwrap() = Some(tx);
+
+                async move {
+                    let _ = rx.await;
+
+                    *completed.lock().unwrap() = true;
+                }
+            });
+        }
+
+        // the only signal of the effect is dropped, which disposes the effect
+        drop(source);
+
+        let _ = sender.lock().unwrap().take().unwrap().send(());
+        spawner.run_all();
+
+        assert!(
+            !*completed.lock().unwrap(),
+            "in-flight future should be aborted with its effect"
+        );
+    }
+}
+// no-coverage:stop
@@ -0,0 +1,242 @@
+use super::{async_effect::InFlight, Runtime, Signal};
+use crate::spawner::LocalFuture;
+use futures::{future::abortable, task::noop_waker_ref};
+use std::{
+    future::Future,
+    panic::AssertUnwindSafe,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+/// State of a value computed asynchronously
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AsyncState<T, E = String> {
+    Loading,
+    Ready(T),
+    Failed(E),
+}
+
+impl<T, E> AsyncState<T, E> {
+    pub fn is_loading(&self) -> bool {
+        matches!(self, Self::Loading)
+    }
+
+    pub fn is_ready(&self) -> bool {
+        matches!(self, Self::Ready(_))
+    }
+
+    pub fn is_failed(&self) -> bool {
+        matches!(self, Self::Failed(_))
+    }
+
+    pub fn value(&self) -> Option<&T> {
+        match self {
+            Self::Ready(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn error(&self) -> Option<&E> {
+        match self {
+            Self::Failed(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl<T, E> From<Result<T, E>> for AsyncState<T, E> {
+    fn from(res: Result<T, E>) -> Self {
+        match res {
+            Ok(value) => Self::Ready(value),
+            Err(err) => Self::Failed(err),
+        }
+    }
+}
+
+pub(super) fn create_async_memo<T, E, F, R>(runtime: Arc<Runtime>, f: F) -> Signal<AsyncState<T, E>>
+where
+    T: Clone + PartialEq + 'static,
+    E: PartialEq + 'static,
+    R: Future<Output = Result<T, E>> + 'static,
+    F: Fn(Option<T>) -> R + 'static, {
+    let state = runtime.create_signal(AsyncState::Loading);
+    let in_flight = InFlight::default();
+
+    {
+        let state = state.clone();
+
+        state.runtime().create_effect(move || {
+            // a new run supersedes the previous one, whose result would be stale
+            in_flight.replace(None);
+
+            let prev = state.untracked_with(|state| state.value().cloned());
+            let mut fut = Box::pin(f(prev));
+
+            // the first poll runs inside the effect, so the signals read before the first await are tracked
+            let mut cx = Context::from_waker(noop_waker_ref());
+
+            match Pin::new(&mut fut).poll(&mut cx) {
+                Poll::Ready(res) => set_state(&state, res.into()),
+                Poll::Pending => {
+                    set_state(&state, AsyncState::Loading);
+
+                    let target = state.downgrade();
+                    let (task, handle) = abortable(fut);
+
+                    in_flight.replace(Some(handle));
+                    state.runtime().spawn(LocalFuture::new(AssertUnwindSafe(async move {
+                        if let Ok(res) = task.await {
+                            target.update_if(|state| update_state(state, res.into()));
+                        }
+                    })));
+                }
+            }
+        });
+    }
+
+    state
+}
+
+fn set_state<T: PartialEq + 'static, E: PartialEq + 'static>(
+    signal: &Signal<AsyncState<T, E>>,
+    next: AsyncState<T, E>,
+) {
+    signal.update_if(|state| update_state(state, next));
+}
+
+fn update_state<T: PartialEq, E: PartialEq>(state: &mut AsyncState<T, E>, next: AsyncState<T, E>) -> bool {
+    let has_diff = *state != next;
+
+    if has_diff {
+        *state = next;
+    }
+
+    has_diff
+}
+
+// no-coverage:start
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signal::tests::{create_queued_runtime, create_runtime};
+    use futures::channel::oneshot;
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_async_memo_ready() {
+        let rt = create_runtime();
+        let query = Arc::clone(&rt).create_signal(2);
+        let result = {
+            let query = query.clone();
+
+            rt.create_async_memo(move |_| {
+                let query = query.get();
+
+                async move {
+                    if query < 0 {
+                        Err("negative".to_string())
+                    } else {
+                        Ok(query * 10)
+                    }
+                }
+            })
+        };
+
+        assert_eq!(result.get(), AsyncState::Ready(20), "value should be computed");
+
+        query.set(3);
+        assert_eq!(
+            result.get(),
+            AsyncState::Ready(30),
+            "value should follow the dependencies"
+        );
+
+        query.set(-1);
+        assert_eq!(
+            result.get(),
+            AsyncState::Failed("negative".to_string()),
+            "error should be exposed"
+        );
+    }
+
+    #[test]
+    fn test_async_memo_aborts_stale_runs() {
+        let (rt, spawner) = create_queued_runtime();
+        let query = Arc::clone(&rt).create_signal(1);
+        let senders = Arc::new(Mutex::new(vec![]));
+        let completed = Arc::new(Mutex::new(vec![]));
+        let result = {
+            let query = query.clone();
+            let senders = Arc::clone(&senders);
+            let completed = Arc::clone(&completed);
+
+            Arc::clone(&rt).create_async_memo(move |_| {
+                let query = query.get();
+                let (tx, rx) = oneshot::channel::<i32>();
+                let completed = Arc::clone(&completed);
+
+                senders.lock().unwrap().push(tx);
+
+                async move {
+                    let res = rx.await.map(|v| v + query).map_err(|_| "cancelled".to_string());
+
+                    completed.lock().unwrap().push(query);
+                    res
+                }
+            })
+        };
+
+        let states = Arc::new(Mutex::new(vec![]));
+
+        {
+            let result = result.clone();
+            let states = Arc::clone(&states);
+
+            rt.create_effect(move || states.lock().unwrap().push(result.get()));
+        }
+
+        assert!(result.get().is_loading(), "value should be loading");
+
+        query.set(100);
+
+        let mut senders = senders.lock().unwrap().drain(..).collect::<Vec<_>>();
+        let latest = senders.pop().unwrap();
+        let stale = senders.pop().unwrap();
+
+        stale.send(1).unwrap();
+        latest.send(2).unwrap();
+        spawner.run_all();
+
+        assert_eq!(result.get(), AsyncState::Ready(102), "latest result should be set");
+        assert_eq!(
+            *states.lock().unwrap(),
+            vec![AsyncState::Loading, AsyncState::Ready(102)],
+            "stale result should be dropped"
+        );
+        assert_eq!(
+            *completed.lock().unwrap(),
+            vec![100],
+            "stale computation should be aborted"
+        );
+    }
+
+    #[test]
+    fn test_async_state() {
+        let state = AsyncState::<i32>::from(Ok(1));
+
+        assert!(state.is_ready(), "state should be ready");
+        assert_eq!(state.value(), Some(&1), "value should be exposed");
+        assert_eq!(state.error(), None, "ready state should have no error");
+
+        let state = AsyncState::<i32>::from(Err("error".to_string()));
+
+        assert!(state.is_failed(), "state should be failed");
+        assert_eq!(
+            state.error().map(String::as_str),
+            Some("error"),
+            "error should be exposed"
+        );
+    }
+}
+// no-coverage:stop
@@ -0,0 +1,179 @@
+use super::{Runtime, Signal, Subscription};
+use std::{
+    cell::RefCell,
+    rc::Rc,
+    sync::{Arc, Mutex},
+};
+
+/// Direction of the updates between a bridged signal and its mirror
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BridgeDirection {
+    /// Updates of the source signal are sent to the mirror
+    #[default]
+    OneWay,
+
+    /// Updates are sent from each side to the other
+    TwoWay,
+}
+
+// version of a side already in sync with the other side, so the notifications of this version are not sent back
+#[derive(Clone, Default)]
+pub(super) struct Echoes(Arc<Mutex<Option<u64>>>);
+
+impl Echoes {
+    /// Runs an update of the side at the given version, which returns `false` when nothing has changed
+    pub(super) fn send(&self, version: u64, update: impl FnOnce() -> bool) {
+        // the version is expected before updating, as the notification can be run synchronously
+        let previous = self.0.lock().unwrap().replace(version + 1);
+
+        if !update() {
+            *self.0.lock().unwrap() = previous;
+        }
+    }
+
+    /// Returns `false` the first time a version of the side is notified, when it has to be sent to the other side
+    pub(super) fn is_synced(&self, version: Option<u64>) -> bool {
+        let Some(version) = version else {
+            return true;
+        };
+
+        let mut synced = self.0.lock().unwrap();
+
+        if *synced == Some(version) {
+            return true;
+        }
+
+        *synced = Some(version);
+        false
+    }
+}
+
+pub(super) fn create_bridge<T: Clone + 'static>(
+    src: &Signal<T>,
+    dst_runtime: &Arc<Runtime>,
+    direction: BridgeDirection,
+) -> Signal<T> {
+    let src_runtime = src.runtime();
+    let mirror = Arc::clone(dst_runtime).create_signal(src.peek());
+    let to_mirror = Echoes::default();
+    let to_src = Echoes::default();
+
+    // each side holds the subscription of the other one, so the bridge is removed when either signal is dropped
+    let backward_slot = Rc::new(RefCell::new(None::<Subscription>));
+
+    let forward = {
+        let source = src.downgrade();
+        let target = mirror.downgrade();
+        let to_mirror = to_mirror.clone();
+        let to_src = to_src.clone();
+        let backward_slot = Rc::clone(&backward_slot);
+
+        src_runtime.create_subscription(src.signal_id(), false, move |value: &T| {
+            let _ = &backward_slot;
+
+            if to_src.is_synced(source.version()) {
+                return;
+            }
+
+            if let Some(version) = target.version() {
+                to_mirror.send(version, || target.set(value.clone()));
+            }
+        })
+    };
+
+    let backward = {
+        let source = mirror.downgrade();
+        let target = src.downgrade();
+
+        dst_runtime.create_subscription(mirror.signal_id(), false, move |value: &T| {
+            let _ = &forward;
+
+            if direction == BridgeDirection::OneWay || to_mirror.is_synced(source.version()) {
+                return;
+            }
+
+            if let Some(version) = target.version() {
+                to_src.send(version, || target.set(value.clone()));
+            }
+        })
+    };
+
+    backward_slot.borrow_mut().replace(backward);
+
+    mirror
+}
+
+// no-coverage:start
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signal::tests::{create_runtime, QueuedRunner};
+
+    #[test]
+    fn test_one_way_bridge() {
+        let rt = create_runtime();
+        let other = create_runtime();
+        let signal = Arc::clone(&rt).create_signal(1);
+        let mirror = Runtime::bridge(&signal, &other);
+
+        assert_eq!(mirror.get(), 1, "mirror should start with the source value");
+        assert_eq!(mirror.runtime(), other, "mirror should be created in the other runtime");
+
+        signal.set(2);
+        assert_eq!(mirror.get(), 2, "source updates should be sent to the mirror");
+
+        mirror.set(3);
+        assert_eq!(signal.get(), 2, "mirror updates should not be sent back");
+    }
+
+    #[test]
+    fn test_two_way_bridge() {
+        let rt = create_runtime();
+        let other = create_runtime();
+        let signal = Arc::clone(&rt).create_signal(1);
+        let mirror = Runtime::bridge_with(&signal, &other, BridgeDirection::TwoWay);
+        let versions = (signal.version(), mirror.version());
+
+        signal.set(2);
+        assert_eq!(mirror.get(), 2, "source updates should be sent to the mirror");
+
+        mirror.set(3);
+        assert_eq!(signal.get(), 3, "mirror updates should be sent to the source");
+
+        assert_eq!(
+            (signal.version(), mirror.version()),
+            (versions.0 + 2, versions.1 + 2),
+            "each update should be applied once on each side"
+        );
+    }
+
+    #[test]
+    fn test_two_way_bridge_with_interleaved_updates() {
+        let runner = QueuedRunner::default();
+        let rt = Runtime::new().with_defer_runner(runner.clone());
+        let other = Runtime::new().with_defer_runner(runner.clone());
+        let signal = Arc::clone(&rt).create_signal(1);
+        let mirror = Runtime::bridge_with(&signal, &other, BridgeDirection::TwoWay);
+
+        signal.set(2);
+        runner.run_all();
+
+        // the mirror is updated before the notification of the forwarded value
+        mirror.set(3);
+        runner.run_all();
+        runner.run_all();
+
+        assert_eq!(signal.get(), 3, "local update should be sent to the source");
+        assert_eq!(mirror.get(), 3, "mirror should keep its local update");
+
+        // concurrent updates of both sides converge to the value sent first
+        signal.set(4);
+        mirror.set(5);
+        runner.run_all();
+        runner.run_all();
+        runner.run_all();
+
+        assert_eq!((signal.get(), mirror.get()), (4, 4), "both sides should converge");
+    }
+}
+// no-coverage:stop
@@ -0,0 +1,34 @@
+/// Change payload describing how the value of a signal has been updated
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Change<K> {
+    Inserted(K),
+    Removed(K),
+    Replaced,
+}
+
+impl<K> Change<K> {
+    pub fn key(&self) -> Option<&K> {
+        match self {
+            Change::Inserted(key) | Change::Removed(key) => Some(key),
+            Change::Replaced => None,
+        }
+    }
+}
+
+// no-coverage:start
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_change_key() {
+        assert_eq!(Change::Inserted(1).key(), Some(&1), "inserted change should have a key");
+        assert_eq!(Change::Removed(2).key(), Some(&2), "removed change should have a key");
+        assert_eq!(
+            Change::<i32>::Replaced.key(),
+            None,
+            "replaced change should not have a key"
+        );
+    }
+}
+// no-coverage:stop
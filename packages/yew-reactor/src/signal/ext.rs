@@ -0,0 +1,508 @@
+use super::Signal;
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap},
+    hash::{BuildHasher, Hash},
+};
+
+/// Mutations of a vector signal, subscribers are only notified when the vector has changed
+pub trait SignalVecExt<T> {
+    fn push(&self, value: T);
+    fn pop(&self) -> Option<T>;
+    /// Returns the value back when the index is out of bounds, the vector is then left untouched
+    fn insert(&self, index: usize, value: T) -> Result<(), T>;
+    /// Returns `None` when the index is out of bounds
+    fn remove(&self, index: usize) -> Option<T>;
+    fn retain(&self, f: impl FnMut(&T) -> bool);
+    fn clear(&self);
+    fn sort_by(&self, compare: impl FnMut(&T, &T) -> Ordering);
+}
+
+impl<T: 'static> SignalVecExt<T> for Signal<Vec<T>> {
+    fn push(&self, value: T) {
+        self.update(|v| v.push(value));
+    }
+
+    fn pop(&self) -> Option<T> {
+        let mut res = None;
+
+        self.update_if(|v| {
+            res = v.pop();
+            res.is_some()
+        });
+
+        res
+    }
+
+    fn insert(&self, index: usize, value: T) -> Result<(), T> {
+        // the bounds are checked before updating, as a panic under the lock would poison it
+        if index > self.untracked_with(Vec::len) {
+            return Err(value);
+        }
+
+        self.update(|v| v.insert(index, value));
+
+        Ok(())
+    }
+
+    fn remove(&self, index: usize) -> Option<T> {
+        if index >= self.untracked_with(Vec::len) {
+            return None;
+        }
+
+        let mut res = None;
+
+        self.update(|v| res = Some(v.remove(index)));
+
+        res
+    }
+
+    fn retain(&self, f: impl FnMut(&T) -> bool) {
+        self.update_if(|v| {
+            let len = v.len();
+            v.retain(f);
+            v.len() != len
+        });
+    }
+
+    fn clear(&self) {
+        self.update_if(|v| {
+            let changed = !v.is_empty();
+            v.clear();
+            changed
+        });
+    }
+
+    fn sort_by(&self, mut compare: impl FnMut(&T, &T) -> Ordering) {
+        self.update_if(|v| {
+            // an already sorted vector is left untouched
+            if v.windows(2).all(|w| compare(&w[0], &w[1]) != Ordering::Greater) {
+                return false;
+            }
+
+            v.sort_by(compare);
+            true
+        });
+    }
+}
+
+/// Mutations of a map signal, subscribers are only notified when the map has changed
+pub trait SignalMapExt<K, V> {
+    fn insert(&self, key: K, value: V) -> Option<V>
+    where
+        V: PartialEq;
+    fn remove(&self, key: &K) -> Option<V>;
+    /// The value is compared before and after `f`, a new entry is always a change
+    fn entry(&self, key: K, default: impl FnOnce() -> V, f: impl FnOnce(&mut V))
+    where
+        V: Clone + PartialEq;
+}
+
+impl<K, V, S> SignalMapExt<K, V> for Signal<HashMap<K, V, S>>
+where
+    K: Hash + Eq + 'static,
+    V: 'static,
+    S: BuildHasher + 'static,
+{
+    fn insert(&self, key: K, value: V) -> Option<V>
+    where
+        V: PartialEq, {
+        let mut res = None;
+
+        self.update_if(|m| match m.get(&key) {
+            // an equal value is left in place, and the given one is returned as the replaced value
+            Some(old) if *old == value => {
+                res = Some(value);
+                false
+            }
+            _ => {
+                res = m.insert(key, value);
+                true
+            }
+        });
+
+        res
+    }
+
+    fn remove(&self, key: &K) -> Option<V> {
+        let mut res = None;
+
+        self.update_if(|m| {
+            res = m.remove(key);
+            res.is_some()
+        });
+
+        res
+    }
+
+    fn entry(&self, key: K, default: impl FnOnce() -> V, f: impl FnOnce(&mut V))
+    where
+        V: Clone + PartialEq, {
+        self.update_if(|m| match m.get_mut(&key) {
+            Some(v) => {
+                let old = v.clone();
+                f(v);
+                *v != old
+            }
+            None => {
+                f(m.entry(key).or_insert_with(default));
+                true
+            }
+        });
+    }
+}
+
+impl<K, V> SignalMapExt<K, V> for Signal<BTreeMap<K, V>>
+where
+    K: Ord + 'static,
+    V: 'static,
+{
+    fn insert(&self, key: K, value: V) -> Option<V>
+    where
+        V: PartialEq, {
+        let mut res = None;
+
+        self.update_if(|m| match m.get(&key) {
+            // an equal value is left in place, and the given one is returned as the replaced value
+            Some(old) if *old == value => {
+                res = Some(value);
+                false
+            }
+            _ => {
+                res = m.insert(key, value);
+                true
+            }
+        });
+
+        res
+    }
+
+    fn remove(&self, key: &K) -> Option<V> {
+        let mut res = None;
+
+        self.update_if(|m| {
+            res = m.remove(key);
+            res.is_some()
+        });
+
+        res
+    }
+
+    fn entry(&self, key: K, default: impl FnOnce() -> V, f: impl FnOnce(&mut V))
+    where
+        V: Clone + PartialEq, {
+        self.update_if(|m| match m.get_mut(&key) {
+            Some(v) => {
+                let old = v.clone();
+                f(v);
+                *v != old
+            }
+            None => {
+                f(m.entry(key).or_insert_with(default));
+                true
+            }
+        });
+    }
+}
+
+/// Mutations of an optional signal, and a derived signal telling if a value is present
+pub trait SignalOptionExt<T> {
+    fn take(&self) -> Option<T>;
+    fn replace(&self, value: T) -> Option<T>
+    where
+        T: PartialEq;
+    fn is_some(&self) -> Signal<bool>;
+}
+
+impl<T: 'static> SignalOptionExt<T> for Signal<Option<T>> {
+    fn take(&self) -> Option<T> {
+        let mut res = None;
+
+        self.update_if(|v| {
+            res = v.take();
+            res.is_some()
+        });
+
+        res
+    }
+
+    fn replace(&self, value: T) -> Option<T>
+    where
+        T: PartialEq, {
+        let mut res = None;
+
+        self.update_if(|v| match v {
+            Some(old) if *old == value => {
+                res = Some(value);
+                false
+            }
+            _ => {
+                res = v.replace(value);
+                true
+            }
+        });
+
+        res
+    }
+
+    fn is_some(&self) -> Signal<bool> {
+        let signal = self.clone();
+
+        self.runtime().create_memo(move |_| signal.with(Option::is_some))
+    }
+}
+
+pub trait SignalBoolExt {
+    fn toggle(&self);
+}
+
+impl SignalBoolExt for Signal<bool> {
+    fn toggle(&self) {
+        self.update(|v| *v = !*v);
+    }
+}
+
+pub trait SignalNumExt<T> {
+    /// Returns `false` when the value would overflow, the value is then left untouched
+    fn increment(&self) -> bool;
+    /// Returns `false` when the value would overflow, the value is then left untouched
+    fn decrement(&self) -> bool;
+    /// Returns `false` when the bounds are invalid, `min` above `max` or NaN, the value is then left untouched
+    fn clamp(&self, min: T, max: T) -> bool;
+}
+
+// the overflow is checked before updating, as a panic under the lock would poison it
+fn step<T: Copy + 'static>(signal: &Signal<T>, f: impl FnOnce(T) -> Option<T>) -> bool {
+    let mut stepped = false;
+
+    signal.update_if(|v| {
+        if let Some(next) = f(*v) {
+            *v = next;
+            stepped = true;
+        }
+
+        stepped
+    });
+
+    stepped
+}
+
+macro_rules! impl_num_ext {
+    (@impl $t:ty, $inc:expr, $dec:expr) => {
+        impl SignalNumExt<$t> for Signal<$t> {
+            fn increment(&self) -> bool {
+                step(self, $inc)
+            }
+
+            fn decrement(&self) -> bool {
+                step(self, $dec)
+            }
+
+            fn clamp(&self, min: $t, max: $t) -> bool {
+                // the bounds are checked before updating, as `clamp` panics on invalid bounds
+                #[allow(clippy::neg_cmp_op_on_partial_ord)]
+                if !(min <= max) {
+                    return false;
+                }
+
+                self.update_if(|v| {
+                    let clamped = <$t>::clamp(*v, min, max);
+                    let changed = clamped != *v;
+                    *v = clamped;
+                    changed
+                });
+
+                true
+            }
+        }
+    };
+    (int => $($t:ty),*) => {
+        $(impl_num_ext!(@impl $t, |v: $t| v.checked_add(1), |v: $t| v.checked_sub(1));)*
+    };
+    (float => $($t:ty),*) => {
+        $(impl_num_ext!(@impl $t, |v: $t| Some(v + 1.0), |v: $t| Some(v - 1.0));)*
+    };
+}
+
+impl_num_ext!(int => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_num_ext!(float => f32, f64);
+
+// no-coverage:start
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signal::tests::{count_notifications, create_runtime};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_vec_ext() {
+        let rt = create_runtime();
+        let list = Arc::clone(&rt).create_signal(vec![3, 1, 2]);
+        let runs = count_notifications(&rt, &list);
+
+        list.push(4);
+        assert_eq!(list.insert(0, 0), Ok(()), "value should be inserted");
+        assert_eq!(list.get(), vec![0, 3, 1, 2, 4], "values should be pushed and inserted");
+        assert_eq!(runs.get(), 3, "push and insert should notify");
+
+        assert_eq!(list.remove(1), Some(3), "removed value should be returned");
+        assert_eq!(list.pop(), Some(4), "popped value should be returned");
+        assert_eq!(runs.get(), 5, "remove and pop should notify");
+
+        assert_eq!(list.insert(9, 9), Err(9), "out of bounds value should be returned back");
+        assert_eq!(list.remove(9), None, "out of bounds index should not be removed");
+        assert_eq!(runs.get(), 5, "out of bounds mutations should not notify");
+
+        list.sort_by(|a, b| b.cmp(a));
+        assert_eq!(list.get(), vec![2, 1, 0], "values should be sorted");
+        assert_eq!(runs.get(), 6, "sorting should notify");
+
+        list.sort_by(|a, b| b.cmp(a));
+        list.retain(|v| *v < 10);
+        assert_eq!(
+            runs.get(),
+            6,
+            "sorting a sorted vector and retaining everything should not notify"
+        );
+
+        list.retain(|v| *v > 0);
+        assert_eq!(list.get(), vec![2, 1], "values should be retained");
+        assert_eq!(runs.get(), 7, "retaining fewer values should notify");
+
+        list.clear();
+        list.clear();
+        assert_eq!(list.pop(), None, "popping an empty vector should return nothing");
+        assert_eq!(runs.get(), 8, "clearing or popping an empty vector should not notify");
+    }
+
+    #[test]
+    fn test_map_ext() {
+        let rt = create_runtime();
+        let map = Arc::clone(&rt).create_signal(HashMap::<&str, i32>::new());
+        let runs = count_notifications(&rt, &map);
+
+        assert_eq!(map.insert("a", 1), None, "inserting a new key should return nothing");
+        assert_eq!(
+            map.insert("a", 2),
+            Some(1),
+            "inserting an existing key should return the old value"
+        );
+        map.entry("b", || 10, |v| *v += 1);
+        map.entry("b", || 10, |v| *v += 1);
+        assert_eq!(
+            map.with(|m| m.get("b").copied()),
+            Some(12),
+            "entry should be inserted then updated"
+        );
+        assert_eq!(runs.get(), 5, "insert and entry should notify");
+
+        assert_eq!(map.insert("a", 2), Some(2), "inserting an equal value should return it");
+        map.entry("b", || 10, |_| ());
+        assert_eq!(
+            runs.get(),
+            5,
+            "inserting an equal value or leaving an entry unchanged should not notify"
+        );
+
+        assert_eq!(map.remove(&"a"), Some(2), "removed value should be returned");
+        assert_eq!(map.remove(&"a"), None, "removing a missing key should return nothing");
+        assert_eq!(runs.get(), 6, "removing a missing key should not notify");
+    }
+
+    #[test]
+    fn test_btree_map_ext() {
+        let rt = create_runtime();
+        let map = Arc::clone(&rt).create_signal(BTreeMap::new());
+
+        map.insert(2, "b");
+        map.insert(1, "a");
+        map.entry(3, || "c", |_| ());
+
+        assert_eq!(
+            map.with(|m| m.values().copied().collect::<Vec<_>>()),
+            vec!["a", "b", "c"],
+            "values should be ordered by key"
+        );
+        assert_eq!(map.remove(&2), Some("b"), "removed value should be returned");
+    }
+
+    #[test]
+    fn test_option_ext() {
+        let rt = create_runtime();
+        let value = Arc::clone(&rt).create_signal(None);
+        let is_some = value.is_some();
+        let runs = count_notifications(&rt, &value);
+
+        assert!(!is_some.get(), "empty option should not be some");
+        assert_eq!(value.take(), None, "taking an empty option should return nothing");
+        assert_eq!(runs.get(), 1, "taking an empty option should not notify");
+
+        assert_eq!(
+            value.replace(1),
+            None,
+            "replacing an empty option should return nothing"
+        );
+        assert!(is_some.get(), "option should be some once replaced");
+        assert_eq!(value.replace(2), Some(1), "replacing should return the old value");
+        assert_eq!(
+            value.replace(2),
+            Some(2),
+            "replacing with an equal value should return it"
+        );
+        assert_eq!(runs.get(), 3, "replacing with an equal value should not notify");
+
+        assert_eq!(value.take(), Some(2), "taken value should be returned");
+        assert!(!is_some.get(), "option should be empty once taken");
+        assert_eq!(runs.get(), 4, "replace and take should notify");
+    }
+
+    #[test]
+    fn test_bool_ext() {
+        let rt = create_runtime();
+        let flag = rt.create_signal(false);
+
+        flag.toggle();
+        assert!(flag.get(), "flag should be toggled on");
+
+        flag.toggle();
+        assert!(!flag.get(), "flag should be toggled off");
+    }
+
+    #[test]
+    fn test_num_ext() {
+        let rt = create_runtime();
+        let count = Arc::clone(&rt).create_signal(5);
+        let ratio = Arc::clone(&rt).create_signal(0.5_f64);
+        let runs = count_notifications(&rt, &count);
+
+        count.increment();
+        count.increment();
+        count.decrement();
+        assert_eq!(count.get(), 6, "count should be incremented and decremented");
+
+        assert!(count.clamp(0, 10), "valid bounds should be applied");
+        assert_eq!(runs.get(), 4, "clamping a value within bounds should not notify");
+
+        assert!(!count.clamp(3, 0), "reversed bounds should be rejected");
+        assert_eq!(runs.get(), 4, "rejected bounds should not notify");
+
+        count.clamp(0, 3);
+        assert_eq!(count.get(), 3, "count should be clamped");
+        assert_eq!(runs.get(), 5, "clamping should notify");
+
+        ratio.increment();
+        ratio.clamp(0.0, 1.0);
+        assert_eq!(ratio.get(), 1.0, "float should be incremented then clamped");
+        assert!(!ratio.clamp(f64::NAN, 1.0), "NaN bound should be rejected");
+        assert_eq!(ratio.get(), 1.0, "float should be left untouched by invalid bounds");
+
+        let byte = Arc::clone(&rt).create_signal(u8::MAX);
+        let byte_runs = count_notifications(&rt, &byte);
+
+        assert!(!byte.increment(), "overflowing increment should be rejected");
+        assert!(byte.decrement(), "decrement should be applied");
+        assert_eq!(byte.get(), 254, "value should only be decremented");
+        assert_eq!(byte_runs.get(), 2, "rejected increment should not notify");
+    }
+}
+// no-coverage:stop
@@ -10,6 +10,10 @@ impl SignalId {
         Self(new_id())
     }
 
+    pub(super) fn from_sequence(sequence: u64) -> Self {
+        Self(Uuid::from_u64_pair(0, sequence))
+    }
+
     pub(super) fn id(&self) -> String {
         self.0.to_string()
     }
@@ -40,6 +44,28 @@ impl Display for EffectId {
     }
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+/// Identifier of a runtime, unique for each created runtime
+pub struct RuntimeId(Uuid);
+
+impl RuntimeId {
+    pub(super) fn new() -> Self {
+        Self(new_id())
+    }
+}
+
+impl Default for RuntimeId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Display for RuntimeId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "runtime:id:{}", self.0)
+    }
+}
+
 // no-coverage:start
 #[cfg(test)]
 mod tests {
@@ -52,11 +78,42 @@ mod tests {
         assert_eq!(format!("{id}"), format!("signal:id:{}", id.0));
     }
 
+    #[test]
+    fn test_signal_id_from_sequence() {
+        assert_eq!(
+            SignalId::from_sequence(42),
+            SignalId::from_sequence(42),
+            "ids from the same sequence number should be equal"
+        );
+
+        assert_eq!(
+            SignalId::from_sequence(42).id(),
+            "00000000-0000-0000-0000-00000000002a",
+            "id should be made from the sequence number"
+        );
+    }
+
     #[test]
     fn test_effect_id_display() {
         let id = EffectId::new();
 
         assert_eq!(format!("{id}"), format!("effect:id:{}", id.0));
     }
+
+    #[test]
+    fn test_runtime_id_display() {
+        let id = RuntimeId::new();
+
+        assert_eq!(format!("{id}"), format!("runtime:id:{}", id.0));
+    }
+
+    #[test]
+    fn test_runtime_id_default_is_unique() {
+        assert_ne!(
+            RuntimeId::default(),
+            RuntimeId::default(),
+            "each default runtime id should be unique"
+        );
+    }
 }
 // no-coverage:stop
@@ -0,0 +1,168 @@
+use super::{bridge::Echoes, Signal};
+use std::{cell::RefCell, rc::Rc, sync::Arc};
+
+impl<T: 'static> Signal<T> {
+    /// Writable signal focused on a part of this signal,
+    /// each side is only notified when the focused part has actually changed
+    pub fn lens<U>(&self, get: impl Fn(&T) -> &U + 'static, get_mut: impl Fn(&mut T) -> &mut U + 'static) -> Signal<U>
+    where
+        U: Clone + PartialEq + 'static, {
+        let runtime = self.runtime();
+        let lens = Arc::clone(&runtime).create_signal(self.with(|v| get(v).clone()));
+
+        // the parent holds the lens subscription and the lens holds the parent subscription,
+        // so the link is removed with the lens, which keeps its parent alive
+        let lens_slot = Rc::new(RefCell::new(None));
+
+        // the updates sent to a side are not sent back to the side they come from
+        let to_lens_echoes = Echoes::default();
+        let to_parent_echoes = Echoes::default();
+
+        let to_lens = {
+            let source = self.downgrade();
+            let target = lens.downgrade();
+            let lens_slot = Rc::clone(&lens_slot);
+            let to_lens_echoes = to_lens_echoes.clone();
+            let to_parent_echoes = to_parent_echoes.clone();
+
+            let read = move |value: &T| get(value).clone();
+
+            runtime.create_subscription_with(self.signal_id(), false, read, move |part: U| {
+                let _ = &lens_slot;
+
+                if to_parent_echoes.is_synced(source.version()) {
+                    return;
+                }
+
+                if let Some(version) = target.version() {
+                    to_lens_echoes.send(version, || {
+                        target.update_if(|v| {
+                            let changed = *v != part;
+                            if changed {
+                                *v = part;
+                            }
+
+                            changed
+                        })
+                    });
+                }
+            })
+        };
+
+        let to_parent = {
+            let source = lens.downgrade();
+            let parent = self.clone();
+
+            runtime.create_subscription(lens.signal_id(), false, move |value: &U| {
+                let _ = &to_lens;
+
+                if to_lens_echoes.is_synced(source.version()) {
+                    return;
+                }
+
+                to_parent_echoes.send(parent.version(), || {
+                    let mut changed = false;
+
+                    parent.update_if(|v| {
+                        let part = get_mut(v);
+                        changed = part != value;
+                        if changed {
+                            *part = value.clone();
+                        }
+
+                        changed
+                    });
+
+                    changed
+                });
+            })
+        };
+
+        lens_slot.borrow_mut().replace(to_parent);
+
+        lens
+    }
+}
+
+// no-coverage:start
+#[cfg(test)]
+mod tests {
+    use crate::signal::tests::{count_notifications, create_runtime};
+    use std::sync::Arc;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Form {
+        name: String,
+        age:  u32,
+    }
+
+    fn create_form() -> Form {
+        Form {
+            name: "Alice".to_string(),
+            age:  30,
+        }
+    }
+
+    #[test]
+    fn test_lens_write_updates_parent() {
+        let rt = create_runtime();
+        let form = Arc::clone(&rt).create_signal(create_form());
+        let name = form.lens(|f| &f.name, |f| &mut f.name);
+        let runs = count_notifications(&rt, &form);
+
+        assert_eq!(name.get(), "Alice", "lens should start with the focused part");
+
+        name.set("Bob".to_string());
+
+        assert_eq!(form.get().name, "Bob", "lens write should update the parent");
+        assert_eq!(runs.get(), 2, "lens write should notify the parent subscribers");
+    }
+
+    #[test]
+    fn test_lens_notified_only_on_focused_change() {
+        let rt = create_runtime();
+        let form = Arc::clone(&rt).create_signal(create_form());
+        let age = form.lens(|f| &f.age, |f| &mut f.age);
+        let runs = count_notifications(&rt, &age);
+
+        form.update(|f| f.name = "Carol".to_string());
+
+        assert_eq!(runs.get(), 1, "lens should not be notified when another part changes");
+
+        form.update(|f| f.age = 31);
+
+        assert_eq!(age.get(), 31, "lens should follow the focused part");
+        assert_eq!(runs.get(), 2, "lens should be notified when the focused part changes");
+    }
+
+    #[test]
+    fn test_lens_keeps_parent_alive() {
+        let rt = create_runtime();
+        let form = Arc::clone(&rt).create_signal(create_form());
+        let parent_id = form.signal_id();
+        let age = form.lens(|f| &f.age, |f| &mut f.age);
+
+        drop(form);
+        age.set(40);
+
+        assert!(rt.is_signal_alive(parent_id), "parent should be kept alive by its lens");
+        assert_eq!(
+            rt.find_value(parent_id)
+                .unwrap()
+                .read()
+                .unwrap()
+                .downcast_ref::<Form>()
+                .map(|f| f.age),
+            Some(40),
+            "lens write should still reach the parent"
+        );
+
+        drop(age);
+
+        assert!(
+            !rt.is_signal_alive(parent_id),
+            "parent should be released with its lens"
+        );
+    }
+}
+// no-coverage:stop
@@ -1,4 +1,4 @@
-use super::{id::SignalId, Runtime};
+use super::{id::SignalId, BufferPolicy, SignalChanged, SignalStream, Runtime};
 use std::{
     fmt::{Display, Debug, Formatter, Result as FmtResult},
     sync::atomic::{AtomicBool, Ordering},
@@ -39,6 +39,10 @@ impl<S: 'static, R: 'static> SignalMap<S, R> {
         Arc::clone(&self.runtime)
     }
 
+    pub fn version(&self) -> u64 {
+        self.runtime.signal_version(self.id)
+    }
+
     pub fn with<O, F: FnOnce(&R) -> O>(&self, f: F) -> O {
         let runtime = self.runtime();
 
@@ -66,11 +70,27 @@ impl<S: 'static, R: 'static> SignalMap<S, R> {
         f(&mapped_value)
     }
 
+    pub fn changed(&self) -> SignalChanged {
+        SignalChanged::new::<S>(&self.runtime, self.id)
+    }
+
+    pub fn to_stream(&self) -> SignalStream<R> {
+        self.to_stream_with(BufferPolicy::Latest)
+    }
+
+    pub fn to_stream_with(&self, policy: BufferPolicy) -> SignalStream<R> {
+        let mapper = Arc::clone(&self.mapper);
+
+        SignalStream::new(&self.runtime, self.id, policy, move |value: &S| mapper(value))
+    }
+
     pub fn with_another<XS, XR, O, F>(&self, other: SignalMap<XS, XR>, f: F) -> O
     where
         XS: 'static,
         XR: 'static,
         F: FnOnce(&R, &XR) -> O, {
+        self.runtime.check_same_runtime(&other.runtime, "combine");
+
         let other = other.clone();
 
         self.with(move |v| other.with(|o| f(v, o)))
@@ -114,7 +134,8 @@ impl<S: 'static, R: Clone + 'static> SignalMap<S, R> {
 
 impl<S: 'static, R: 'static> PartialEq for SignalMap<S, R> {
     fn eq(&self, other: &Self) -> bool {
-        self.id == other.id
+        // deterministic ids are only unique within a runtime
+        self.runtime == other.runtime && self.id == other.id
     }
 }
 
@@ -138,7 +159,10 @@ impl<S: 'static, R: 'static> Clone for SignalMap<S, R> {
 
 impl<S: 'static, R: 'static> Drop for SignalMap<S, R> {
     fn drop(&mut self) {
-        self.runtime.clean_signal(self.id);
+        // a handle released by an effect does not hold a reference anymore
+        if !self.registered.load(Ordering::SeqCst) {
+            self.runtime.clean_signal(self.id);
+        }
     }
 }
 
@@ -185,5 +209,18 @@ mod tests {
 
         assert_eq!(result, 3, "signal value should be equal to the sum of the two signals");
     }
+
+    #[test]
+    fn test_signal_map_version() {
+        let rt = create_runtime();
+        let signal = Arc::clone(&rt).create_signal(21);
+        let map = SignalMap::new(Arc::clone(&rt), signal.signal_id(), |v: &i32| *v * 2);
+
+        assert_eq!(map.version(), 0, "initial version should be 0");
+
+        signal.set(42);
+        assert_eq!(map.version(), 1, "mapping should share the version of its signal");
+        assert_eq!(map.get(), 84, "mapping should return the mapped value");
+    }
 }
 // no-coverage:stop
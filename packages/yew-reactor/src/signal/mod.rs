@@ -1,22 +1,154 @@
+mod async_effect;
+mod async_memo;
+mod bridge;
+mod change;
+mod ext;
 mod id;
 mod keyed_collection;
+mod lens;
 mod mapping;
+mod ops;
+mod reactive_map;
 mod runtime;
+mod selector;
+mod sender;
 mod signal;
+mod signal_vec;
+mod store;
+mod stream;
+mod subscription;
+mod trigger;
+mod weak;
 
-pub use self::{signal::Signal, mapping::SignalMap, runtime::Runtime, keyed_collection::KeyedCollection};
+#[cfg(feature = "serde")]
+mod serialization;
+
+#[cfg(feature = "serde")]
+mod snapshot;
+
+pub use self::{
+    signal::Signal,
+    mapping::SignalMap,
+    runtime::Runtime,
+    id::RuntimeId,
+    keyed_collection::KeyedCollection,
+    change::Change,
+    bridge::BridgeDirection,
+    subscription::Subscription,
+    sender::SignalSender,
+    stream::{BufferPolicy, SignalChanged, SignalDropped, SignalStream},
+    trigger::Trigger,
+    selector::Selector,
+    signal_vec::{SignalVec, VecDiff},
+    reactive_map::ReactiveMap,
+    async_memo::AsyncState,
+    store::StoreScope,
+    ext::{SignalBoolExt, SignalMapExt, SignalNumExt, SignalOptionExt, SignalVecExt},
+};
+
+#[cfg(feature = "serde")]
+pub use self::{
+    snapshot::{StateSnapshot, StateSerializer, JsonStateSerializer},
+    serialization::SignalSeed,
+};
+
+pub(crate) use self::weak::WeakSignal;
+
+#[cfg(feature = "serde")]
+pub(crate) use self::serialization::current_runtime_for;
 
 // no-coverage:start
 #[cfg(test)]
 pub(crate) mod tests {
     use super::*;
-    use crate::{spawner::generators::FuturesSpawner, defer::runners::RunnerForTests};
-    use std::sync::Arc;
+    use crate::{
+        spawner::{generators::FuturesSpawner, FutureVoid, SpawnGenerator},
+        defer::{runners::RunnerForTests, DeferRunner},
+    };
+
+    use futures::executor::block_on;
+    use std::{
+        sync::{Arc, Mutex},
+        mem::take,
+        rc::Rc,
+    };
 
     pub fn create_runtime() -> Arc<Runtime> {
         Runtime::new()
             .with_spawn_generator(FuturesSpawner::new())
             .with_defer_runner(RunnerForTests::new())
     }
+
+    /// Runtime whose spawned futures are only run by `QueuedSpawner::run_all`
+    pub fn create_queued_runtime() -> (Arc<Runtime>, QueuedSpawner) {
+        let spawner = QueuedSpawner::default();
+        let rt = Runtime::new()
+            .with_spawn_generator(spawner.clone())
+            .with_defer_runner(RunnerForTests::new());
+
+        (rt, spawner)
+    }
+
+    /// Counts the runs of an effect calling `f`, including its first run
+    pub fn count_runs(rt: &Arc<Runtime>, f: impl Fn() + 'static) -> Signal<usize> {
+        let runs = Arc::clone(rt).create_signal(0);
+
+        {
+            let runs = runs.clone();
+            rt.create_effect(move || {
+                f();
+                runs.untracked_update(|n| *n += 1);
+            });
+        }
+
+        runs
+    }
+
+    /// Counts the runs of an effect reading the signal, including its first run
+    pub fn count_notifications<T: 'static>(rt: &Arc<Runtime>, signal: &Signal<T>) -> Signal<usize> {
+        let signal = signal.clone();
+
+        count_runs(rt, move || signal.with(|_| ()))
+    }
+
+    #[derive(Clone, Default)]
+    pub struct QueuedSpawner(Rc<Mutex<Vec<FutureVoid>>>);
+
+    impl QueuedSpawner {
+        pub fn run_all(&self) {
+            let futures = take(&mut *self.0.lock().unwrap());
+
+            for fut in futures {
+                block_on(fut);
+            }
+        }
+    }
+
+    impl SpawnGenerator for QueuedSpawner {
+        fn spawn(&self, fut: FutureVoid) {
+            self.0.lock().unwrap().push(fut);
+        }
+    }
+
+    type DeferredJobs = Rc<Mutex<Vec<Arc<dyn Fn()>>>>;
+
+    #[derive(Clone, Default)]
+    pub struct QueuedRunner(DeferredJobs);
+
+    impl QueuedRunner {
+        pub fn run_all(&self) {
+            let jobs = take(&mut *self.0.lock().unwrap());
+
+            for job in jobs {
+                job();
+            }
+        }
+    }
+
+    impl DeferRunner for QueuedRunner {
+        fn run(&self, f: Arc<dyn Fn()>) {
+            self.0.lock().unwrap().push(f);
+        }
+    }
 }
 // no-coverage:stop
@@ -0,0 +1,277 @@
+use super::Signal;
+use std::ops::{Add, Div, Mul, Neg, Not, Rem, Sub};
+
+macro_rules! impl_binary_op {
+    ($($op:ident::$method:ident),* $(,)?) => {
+        $(
+            impl<T, O> $op<&Signal<T>> for &Signal<T>
+            where
+                T: $op<Output = O> + Clone + 'static,
+                O: PartialEq + 'static,
+            {
+                type Output = Signal<O>;
+
+                fn $method(self, rhs: &Signal<T>) -> Signal<O> {
+                    self.runtime().check_same_runtime(&rhs.runtime(), "combine");
+
+                    let lhs = self.clone();
+                    let rhs = rhs.clone();
+
+                    self.runtime().create_memo(move |_| $op::$method(lhs.get(), rhs.get()))
+                }
+            }
+
+            impl<T, O> $op<Signal<T>> for Signal<T>
+            where
+                T: $op<Output = O> + Clone + 'static,
+                O: PartialEq + 'static,
+            {
+                type Output = Signal<O>;
+
+                fn $method(self, rhs: Signal<T>) -> Signal<O> {
+                    $op::$method(&self, &rhs)
+                }
+            }
+
+            impl<T, O> $op<T> for &Signal<T>
+            where
+                T: $op<Output = O> + Clone + 'static,
+                O: PartialEq + 'static,
+            {
+                type Output = Signal<O>;
+
+                fn $method(self, rhs: T) -> Signal<O> {
+                    let lhs = self.clone();
+
+                    self.runtime().create_memo(move |_| $op::$method(lhs.get(), rhs.clone()))
+                }
+            }
+
+            impl<T, O> $op<T> for Signal<T>
+            where
+                T: $op<Output = O> + Clone + 'static,
+                O: PartialEq + 'static,
+            {
+                type Output = Signal<O>;
+
+                fn $method(self, rhs: T) -> Signal<O> {
+                    $op::$method(&self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_unary_op {
+    ($($op:ident::$method:ident),* $(,)?) => {
+        $(
+            impl<T, O> $op for &Signal<T>
+            where
+                T: $op<Output = O> + Clone + 'static,
+                O: PartialEq + 'static,
+            {
+                type Output = Signal<O>;
+
+                fn $method(self) -> Signal<O> {
+                    let signal = self.clone();
+
+                    self.runtime().create_memo(move |_| $op::$method(signal.get()))
+                }
+            }
+
+            impl<T, O> $op for Signal<T>
+            where
+                T: $op<Output = O> + Clone + 'static,
+                O: PartialEq + 'static,
+            {
+                type Output = Signal<O>;
+
+                fn $method(self) -> Signal<O> {
+                    $op::$method(&self)
+                }
+            }
+        )*
+    };
+}
+
+impl_binary_op!(Add::add, Sub::sub, Mul::mul, Div::div, Rem::rem);
+impl_unary_op!(Neg::neg, Not::not);
+
+impl<T: 'static> Signal<T> {
+    fn compare(&self, f: impl Fn(&T) -> bool + 'static) -> Signal<bool> {
+        let signal = self.clone();
+
+        self.runtime().create_memo(move |_| signal.with(&f))
+    }
+}
+
+impl<T: PartialEq + 'static> Signal<T> {
+    pub fn eq_to(&self, value: T) -> Signal<bool> {
+        self.compare(move |v| *v == value)
+    }
+
+    pub fn ne_to(&self, value: T) -> Signal<bool> {
+        self.compare(move |v| *v != value)
+    }
+}
+
+impl<T: PartialOrd + 'static> Signal<T> {
+    pub fn gt(&self, value: T) -> Signal<bool> {
+        self.compare(move |v| *v > value)
+    }
+
+    pub fn ge(&self, value: T) -> Signal<bool> {
+        self.compare(move |v| *v >= value)
+    }
+
+    pub fn lt(&self, value: T) -> Signal<bool> {
+        self.compare(move |v| *v < value)
+    }
+
+    pub fn le(&self, value: T) -> Signal<bool> {
+        self.compare(move |v| *v <= value)
+    }
+}
+
+// no-coverage:start
+#[cfg(test)]
+mod tests {
+    use crate::signal::tests::{count_notifications, create_runtime};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_binary_ops_between_signals() {
+        let rt = create_runtime();
+        let a = Arc::clone(&rt).create_signal(6);
+        let b = Arc::clone(&rt).create_signal(3);
+
+        let sum = &a + &b;
+        let diff = &a - &b;
+        let product = &a * &b;
+        let quotient = &a / &b;
+        let remainder = &a % &b;
+
+        assert_eq!(sum.get(), 9, "sum should be computed");
+        assert_eq!(diff.get(), 3, "difference should be computed");
+        assert_eq!(product.get(), 18, "product should be computed");
+        assert_eq!(quotient.get(), 2, "quotient should be computed");
+        assert_eq!(remainder.get(), 0, "remainder should be computed");
+
+        b.set(4);
+
+        assert_eq!(sum.get(), 10, "sum should follow the operands");
+        assert_eq!(diff.get(), 2, "difference should follow the operands");
+        assert_eq!(product.get(), 24, "product should follow the operands");
+        assert_eq!(quotient.get(), 1, "quotient should follow the operands");
+        assert_eq!(remainder.get(), 2, "remainder should follow the operands");
+    }
+
+    #[test]
+    fn test_binary_ops_with_values() {
+        let rt = create_runtime();
+        let a = Arc::clone(&rt).create_signal(2);
+
+        let doubled = &a * 2;
+        let chained = a.clone() + 1 - 3;
+
+        assert_eq!(doubled.get(), 4, "signal should be multiplied by the value");
+        assert_eq!(chained.get(), 0, "operations should be chained");
+
+        a.set(5);
+
+        assert_eq!(doubled.get(), 10, "product should follow the signal");
+        assert_eq!(chained.get(), 3, "chained operations should follow the signal");
+    }
+
+    #[test]
+    fn test_binary_ops_with_floats() {
+        let rt = create_runtime();
+        let price = Arc::clone(&rt).create_signal(10.0);
+        let quantity = Arc::clone(&rt).create_signal(2.0);
+
+        let total = (&price * &quantity) * 1.5;
+
+        assert_eq!(total.get(), 30.0, "total should be computed");
+
+        quantity.set(3.0);
+
+        assert_eq!(total.get(), 45.0, "total should follow the operands");
+    }
+
+    #[test]
+    fn test_unary_ops() {
+        let rt = create_runtime();
+        let value = Arc::clone(&rt).create_signal(3);
+        let flag = Arc::clone(&rt).create_signal(true);
+
+        let negated = -&value;
+        let inverted = !flag.clone();
+
+        assert_eq!(negated.get(), -3, "value should be negated");
+        assert!(!inverted.get(), "flag should be inverted");
+
+        value.set(-4);
+        flag.set(false);
+
+        assert_eq!(negated.get(), 4, "negation should follow the signal");
+        assert!(inverted.get(), "inversion should follow the signal");
+    }
+
+    #[test]
+    fn test_comparisons() {
+        let rt = create_runtime();
+        let value = Arc::clone(&rt).create_signal(5);
+
+        let gt = value.gt(5);
+        let ge = value.ge(5);
+        let lt = value.lt(5);
+        let le = value.le(5);
+        let eq = value.eq_to(5);
+        let ne = value.ne_to(5);
+
+        assert_eq!(
+            (gt.get(), ge.get(), lt.get(), le.get(), eq.get(), ne.get()),
+            (false, true, false, true, true, false),
+            "comparisons should match the initial value"
+        );
+
+        value.set(7);
+
+        assert_eq!(
+            (gt.get(), ge.get(), lt.get(), le.get(), eq.get(), ne.get()),
+            (true, true, false, false, false, true),
+            "comparisons should follow the signal"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Cross-runtime usage: cannot combine")]
+    fn test_cross_runtime_binary_op() {
+        let a = create_runtime().create_signal(1);
+        let b = create_runtime().create_signal(2);
+
+        let _ = &a + &b;
+    }
+
+    #[test]
+    fn test_derived_signal_is_memoized() {
+        let rt = create_runtime();
+        let value = Arc::clone(&rt).create_signal(1);
+        let positive = value.gt(0);
+        let runs = count_notifications(&rt, &positive);
+
+        value.set(2);
+        value.set(3);
+
+        assert_eq!(
+            runs.get(),
+            1,
+            "effect should not re-run while the comparison is unchanged"
+        );
+
+        value.set(-1);
+
+        assert_eq!(runs.get(), 2, "effect should re-run when the comparison changes");
+    }
+}
+// no-coverage:stop
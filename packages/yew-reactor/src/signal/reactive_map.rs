@@ -0,0 +1,342 @@
+use super::{Change, Runtime, Signal};
+use indexmap::IndexSet;
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    hash::Hash,
+    sync::Arc,
+};
+
+/// Map holding a signal per key, an update of a value only notifies the subscribers of its key,
+/// and the keys signal is only notified when a key is inserted or removed
+pub struct ReactiveMap<K: 'static, V: 'static> {
+    keys:    Signal<IndexSet<K>>,
+    entries: Signal<HashMap<K, Signal<V>>>,
+}
+
+impl<K: Clone + Eq + Hash + 'static, V: 'static> ReactiveMap<K, V> {
+    pub(super) fn new(runtime: Arc<Runtime>, values: impl IntoIterator<Item = (K, V)>) -> Self {
+        let mut keys = IndexSet::new();
+        let mut entries = HashMap::new();
+
+        for (key, value) in values {
+            let signal = Arc::clone(&runtime).create_signal(value);
+
+            if entries.insert(key.clone(), signal).is_none() {
+                keys.insert(key);
+            }
+        }
+
+        Self {
+            keys:    Arc::clone(&runtime).create_signal(keys),
+            entries: runtime.create_signal(entries),
+        }
+    }
+
+    pub fn runtime(&self) -> Arc<Runtime> {
+        self.keys.runtime()
+    }
+
+    /// Keys in insertion order, the `Inserted` and `Removed` changes are emitted on membership changes
+    pub fn keys_signal(&self) -> Signal<IndexSet<K>> {
+        self.keys.clone()
+    }
+
+    pub fn keys(&self) -> Vec<K> {
+        self.with_keys(|keys| keys.iter().cloned().collect())
+    }
+
+    pub fn len(&self) -> usize {
+        self.with_keys(IndexSet::len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.with_keys(IndexSet::is_empty)
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.with_keys(|keys| keys.contains(key))
+    }
+
+    /// Signal of the key, a missing key tracks the keys signal to be notified when it is inserted
+    pub fn get_signal(&self, key: &K) -> Option<Signal<V>> {
+        let signal = self.entry(key);
+
+        if signal.is_none() {
+            self.with_keys(|_| ());
+        }
+
+        signal
+    }
+
+    /// Inserts or replaces the value of the key, and returns `true` if the key is new
+    pub fn insert(&self, key: K, value: V) -> bool {
+        match self.entry(&key) {
+            Some(signal) => {
+                signal.set(value);
+
+                false
+            }
+            None => {
+                let signal = self.runtime().create_signal(value);
+
+                self.entries.untracked_update(|entries| {
+                    entries.insert(key.clone(), signal);
+                });
+                self.keys.update_with_change(|keys| {
+                    keys.insert(key.clone());
+
+                    Change::Inserted(key)
+                });
+
+                true
+            }
+        }
+    }
+
+    /// Removes the key, and returns its signal, the effects reading the key are disposed when it is dropped
+    pub fn remove(&self, key: &K) -> Option<Signal<V>> {
+        let mut removed = None;
+
+        self.entries.untracked_update(|entries| removed = entries.remove(key));
+
+        let removed = removed?;
+
+        self.keys.update_with_change(|keys| {
+            keys.shift_remove(key);
+
+            Change::Removed(key.clone())
+        });
+
+        Some(removed)
+    }
+
+    /// Updates the value of the key, and returns `false` if the key is missing
+    pub fn update(&self, key: &K, f: impl FnOnce(&mut V)) -> bool {
+        match self.entry(key) {
+            Some(signal) => {
+                signal.update(f);
+
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn link_to(&self, source: &ReactiveMap<K, V>) {
+        self.entries.link_to(&source.entries);
+        self.keys.link_to(&source.keys);
+    }
+
+    pub fn clear(&self) {
+        let keys = self.keys.untracked_with(IndexSet::clone);
+
+        for key in keys {
+            self.remove(&key);
+        }
+    }
+
+    fn with_keys<O>(&self, f: impl FnOnce(&IndexSet<K>) -> O) -> O {
+        self.release_in_effect();
+        self.keys.with(f)
+    }
+
+    // the handles of a map read by an effect are released as those of a signal, so the effect does not keep it alive
+    fn release_in_effect(&self) {
+        self.keys.release_in_effect();
+        self.entries.release_in_effect();
+    }
+
+    // the entries are never tracked, the subscribers follow either the keys or a single value
+    fn entry(&self, key: &K) -> Option<Signal<V>> {
+        self.entries.untracked_with(|entries| entries.get(key).cloned())
+    }
+}
+
+impl<K: Clone + Eq + Hash + 'static, V: Clone + 'static> ReactiveMap<K, V> {
+    pub fn get(&self, key: &K) -> Option<V> {
+        self.release_in_effect();
+
+        // the value signal is owned by the entries, so its handle is not released
+        self.get_signal(key).map(|signal| {
+            signal.track();
+            signal.untracked_with(V::clone)
+        })
+    }
+}
+
+impl<K: 'static, V: 'static> Clone for ReactiveMap<K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            keys:    self.keys.clone(),
+            entries: self.entries.clone(),
+        }
+    }
+}
+
+impl<K: 'static, V: 'static> PartialEq for ReactiveMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.keys == other.keys
+    }
+}
+
+impl<K: 'static, V: 'static> Eq for ReactiveMap<K, V> {}
+
+impl<K: 'static, V: 'static> Debug for ReactiveMap<K, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "ReactiveMap[{}]", self.keys.id())
+    }
+}
+
+// no-coverage:start
+#[cfg(test)]
+mod tests {
+    use crate::signal::{
+        tests::{count_runs, create_runtime, QueuedRunner},
+        Change,
+        Runtime,
+    };
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_reactive_map_per_key_notifications() {
+        let rt = create_runtime();
+        let map = Arc::clone(&rt).create_reactive_map([("a", 1), ("b", 2)]);
+        let a_runs = count_runs(&rt, {
+            let map = map.clone();
+            move || {
+                map.get(&"a");
+            }
+        });
+        let b_runs = count_runs(&rt, {
+            let map = map.clone();
+            move || {
+                map.get(&"b");
+            }
+        });
+        let keys_runs = count_runs(&rt, {
+            let map = map.clone();
+            move || {
+                map.len();
+            }
+        });
+
+        assert!(map.update(&"a", |v| *v += 10), "existing key should be updated");
+        assert!(!map.insert("a", 20), "existing key should be replaced");
+
+        assert_eq!(map.get(&"a"), Some(20), "value should be updated");
+        assert_eq!(a_runs.get(), 3, "key subscribers should be notified");
+        assert_eq!(b_runs.get(), 1, "other keys should not be notified");
+        assert_eq!(
+            keys_runs.get(),
+            1,
+            "keys should not be notified without membership change"
+        );
+
+        assert!(map.insert("c", 3), "new key should be inserted");
+        assert_eq!(keys_runs.get(), 2, "keys should be notified when a key is inserted");
+        assert_eq!(b_runs.get(), 1, "other keys should not be notified on insertion");
+
+        assert_eq!(
+            map.remove(&"b").map(|s| s.get()),
+            Some(2),
+            "removed signal should be returned"
+        );
+        assert!(map.remove(&"b").is_none(), "missing key should not be removed");
+        assert_eq!(keys_runs.get(), 3, "keys should be notified when a key is removed");
+        assert_eq!(map.keys(), vec!["a", "c"], "keys should keep the insertion order");
+        assert!(!map.update(&"b", |v| *v += 1), "missing key should not be updated");
+    }
+
+    #[test]
+    fn test_reactive_map_missing_key() {
+        let rt = create_runtime();
+        let map = Arc::clone(&rt).create_reactive_map::<String, i32>([]);
+        let value = Arc::clone(&rt).create_signal(None);
+
+        {
+            let map = map.clone();
+            let value = value.clone();
+            rt.create_effect(move || {
+                value.set(map.get(&"x".to_string()));
+            });
+        }
+
+        assert_eq!(value.get(), None, "missing key should have no value");
+
+        map.insert("x".to_string(), 5);
+
+        assert_eq!(value.get(), Some(5), "missing key should be notified when inserted");
+
+        let other = Arc::clone(&rt).create_reactive_map([(1, 'a'), (2, 'b')]);
+        other.clear();
+
+        assert!(other.is_empty(), "map should be cleared");
+        assert!(!other.contains_key(&1), "cleared key should be missing");
+    }
+
+    #[test]
+    fn test_reactive_map_pending_changes() {
+        let runner = QueuedRunner::default();
+        let rt = Runtime::new().with_defer_runner(runner.clone());
+        let map = Arc::clone(&rt).create_reactive_map([("a", 1)]);
+        let changes = Arc::new(Mutex::new(vec![]));
+
+        map.insert("b", 2);
+
+        // a watcher created before the delivery reads the keys with the inserted key
+        let _subscription = {
+            let changes = Arc::clone(&changes);
+
+            rt.create_change_effect(&map.keys_signal(), move |change: &Change<&str>| {
+                changes.lock().unwrap().push(change.clone());
+            })
+        };
+
+        map.remove(&"a");
+        runner.run_all();
+
+        assert_eq!(
+            *changes.lock().unwrap(),
+            vec![Change::Removed("a")],
+            "watcher should only get the changes emitted after its creation"
+        );
+        assert_eq!(map.keys(), vec!["b"], "keys should keep the insertion order");
+    }
+
+    #[test]
+    fn test_reactive_map_drop() {
+        let rt = create_runtime();
+        let map = Arc::clone(&rt).create_reactive_map([("a", 1)]);
+        let value = map.get_signal(&"a").unwrap();
+        let ids = (map.keys.signal_id(), map.entries.signal_id(), value.signal_id());
+        let effect_state = Arc::new(());
+
+        drop(value);
+
+        {
+            let map = map.clone();
+            let effect_state = Arc::clone(&effect_state);
+
+            rt.create_effect(move || {
+                let _state = &effect_state;
+
+                map.len();
+                map.get(&"a");
+            });
+        }
+
+        drop(map);
+
+        assert!(
+            !rt.is_signal_alive(ids.0) && !rt.is_signal_alive(ids.1) && !rt.is_signal_alive(ids.2),
+            "signals of the map should be removed once the map is dropped"
+        );
+        assert_eq!(
+            Arc::strong_count(&effect_state),
+            1,
+            "effects reading the map should be removed with it"
+        );
+    }
+}
+// no-coverage:stop
@@ -1,43 +1,93 @@
 use super::{
-    id::{SignalId, EffectId},
+    async_effect::create_async_effect,
+    async_memo::create_async_memo,
+    bridge::create_bridge,
+    sender::UpdateInbox,
+    id::{SignalId, EffectId, RuntimeId},
+    BridgeDirection,
+    AsyncState,
     KeyedCollection,
+    ReactiveMap,
+    Selector,
+    StoreScope,
+    SignalVec,
+    Subscription,
+    WeakSignal,
+    Trigger,
+    SignalSender,
     Signal,
 };
 
 use crate::{
-    spawner::{Spawner, SpawnGenerator},
+    spawner::{Spawner, SpawnGenerator, LocalFuture},
     defer::{DeferManager, DeferRunner},
     action::Action,
     css::CssClasses,
 };
 
-use uuid::Uuid;
+#[cfg(feature = "serde")]
+use super::{
+    snapshot::{JsonStateSerializer, StateRegistry, StateSerializer, StateSnapshot},
+    serialization::{current_runtime, CurrentRuntimeGuard},
+};
+
+#[cfg(feature = "serde")]
+use serde::{de::DeserializeOwned, Serialize};
+
+use futures::{
+    future::{abortable, select, AbortHandle},
+    channel::oneshot,
+    FutureExt,
+    Stream,
+    StreamExt,
+};
 use std::{
-    sync::atomic::{AtomicUsize, Ordering},
+    sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+    panic::{AssertUnwindSafe, UnwindSafe},
     collections::{HashMap, HashSet},
     fmt::{Debug, Formatter},
-    sync::{Arc, RwLock},
-    panic::UnwindSafe,
+    hash::Hash,
+    sync::{Arc, Mutex, RwLock},
     future::Future,
+    mem::take,
+    pin::pin,
     any::Any,
 };
 
 type SignalValue = Arc<RwLock<dyn Any>>;
 type EffectFn = Arc<dyn Fn()>;
+type ChangeFn = Arc<dyn Fn(&dyn Any)>;
+type SpawnedTasks = Arc<RwLock<HashMap<usize, AbortHandle>>>;
 
 #[derive(Default)]
 pub struct Runtime {
-    id:                 Uuid,
+    id:                 RuntimeId,
     spawner:            Spawner,
     defer_manager:      DeferManager,
     signal_values:      RwLock<HashMap<SignalId, SignalValue>>,
     signal_refs:        RwLock<HashMap<SignalId, AtomicUsize>>,
+    signal_versions:    RwLock<HashMap<SignalId, u64>>,
+    global_version:     AtomicU64,
     running_effect:     RwLock<Option<EffectId>>,
     signal_links:       RwLock<HashMap<SignalId, SignalId>>,
     reverse_links:      RwLock<HashMap<SignalId, HashSet<SignalId>>>,
     signal_subscribers: RwLock<HashMap<SignalId, HashSet<EffectId>>>,
     effects:            RwLock<HashMap<EffectId, EffectFn>>,
+    change_effects:     RwLock<HashMap<SignalId, HashMap<EffectId, ChangeFn>>>,
     pending_remove:     RwLock<Option<HashSet<SignalId>>>,
+    spawned_tasks:      SpawnedTasks,
+    next_task_id:       AtomicUsize,
+    disposed:           Arc<AtomicBool>,
+    deterministic_ids:  AtomicBool,
+    next_signal_id:     AtomicU64,
+    stores:             StoreScope,
+    sent_updates:       UpdateInbox,
+
+    #[cfg(feature = "serde")]
+    states: StateRegistry,
+
+    #[cfg(feature = "serde")]
+    retain_states: AtomicBool,
 }
 
 impl Runtime {
@@ -45,6 +95,10 @@ impl Runtime {
         Arc::new(Self::default())
     }
 
+    pub fn id(&self) -> RuntimeId {
+        self.id
+    }
+
     pub fn with_spawn_generator(self: Arc<Runtime>, generator: impl SpawnGenerator + 'static) -> Arc<Self> {
         self.spawner.set_generator(generator);
         self
@@ -55,6 +109,11 @@ impl Runtime {
         self
     }
 
+    pub fn with_deterministic_ids(self: Arc<Runtime>) -> Arc<Self> {
+        self.deterministic_ids.store(true, Ordering::SeqCst);
+        self
+    }
+
     pub fn spawner(&self) -> &Spawner {
         &self.spawner
     }
@@ -64,19 +123,125 @@ impl Runtime {
     }
 
     pub(crate) fn spawn<F: Future<Output = ()> + Send + UnwindSafe + 'static>(&self, f: F) {
-        self.spawner.spawn(f)
+        self.check_not_disposed();
+
+        // the task is registered, so it can be aborted when the runtime is disposed
+        let (task, handle) = abortable(f);
+        let task_id = self.next_task_id.fetch_add(1, Ordering::SeqCst);
+        let tasks = Arc::clone(&self.spawned_tasks);
+
+        tasks.write().unwrap().insert(task_id, handle);
+        self.spawner.spawn(AssertUnwindSafe(async move {
+            let _ = task.await;
+
+            tasks.write().unwrap().remove(&task_id);
+        }))
     }
 
     pub(super) fn defer<F: Fn() + UnwindSafe + 'static>(&self, f: F) {
-        self.defer_manager.run(f)
+        let disposed = Arc::clone(&self.disposed);
+
+        // pending deferred jobs are dropped once the runtime is disposed
+        self.defer_manager.run(move || {
+            if !disposed.load(Ordering::SeqCst) {
+                f();
+            }
+        })
+    }
+
+    pub fn is_disposed(&self) -> bool {
+        self.disposed.load(Ordering::SeqCst)
+    }
+
+    pub(super) fn check_not_disposed(&self) {
+        if self.is_disposed() {
+            panic!("Disposed runtime: the runtime `{}` can not be used anymore", self.id);
+        }
+    }
+
+    pub fn dispose(&self) {
+        if self.disposed.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        // close the senders, their updates would never be applied
+        self.sent_updates.close_all();
+
+        // cancel spawned futures
+        for (_, handle) in self.spawned_tasks.write().unwrap().drain() {
+            handle.abort();
+        }
+
+        // take all the content of the runtime before dropping it,
+        // because dropping effects and values can drop signals which come back to the runtime
+        let effects = take(&mut *self.effects.write().unwrap());
+        let change_effects = take(&mut *self.change_effects.write().unwrap());
+        let values = take(&mut *self.signal_values.write().unwrap());
+        let stores = self.stores.clear();
+
+        self.signal_refs.write().unwrap().clear();
+        self.signal_versions.write().unwrap().clear();
+        self.signal_links.write().unwrap().clear();
+        self.reverse_links.write().unwrap().clear();
+        self.signal_subscribers.write().unwrap().clear();
+        self.running_effect.write().unwrap().take();
+        self.pending_remove.write().unwrap().take();
+
+        drop(effects);
+        drop(change_effects);
+        drop(values);
+        drop(stores);
+    }
+
+    pub(super) fn check_same_runtime(&self, other: &Runtime, operation: &str) {
+        if self.id != other.id {
+            panic!(
+                "Cross-runtime usage: cannot {operation} a signal of the runtime `{}` from the runtime `{}`",
+                other.id, self.id,
+            );
+        }
     }
 
     pub(super) fn get_value(&self, id: &SignalId) -> SignalValue {
+        self.check_not_disposed();
+
         Arc::clone(self.signal_values.read().unwrap().get(id).unwrap())
     }
 
+    #[cfg(any(test, feature = "serde"))]
+    pub(super) fn find_value(&self, id: SignalId) -> Option<SignalValue> {
+        let signal_id = self.get_source_id(id);
+
+        self.signal_values.read().unwrap().get(&signal_id).cloned()
+    }
+
+    pub fn global_version(&self) -> u64 {
+        self.global_version.load(Ordering::SeqCst)
+    }
+
+    pub(super) fn signal_version(&self, id: SignalId) -> u64 {
+        let signal_id = self.get_source_id(id);
+
+        self.signal_versions
+            .read()
+            .unwrap()
+            .get(&signal_id)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub(super) fn bump_version(&self, id: SignalId) {
+        // the version is shared by all the signals linked to the same value
+        let signal_id = self.get_source_id(id);
+
+        *self.signal_versions.write().unwrap().entry(signal_id).or_default() += 1;
+        self.global_version.fetch_add(1, Ordering::SeqCst);
+    }
+
     pub fn create_signal<T: 'static>(self: Arc<Self>, value: T) -> Signal<T> {
-        let id = SignalId::new();
+        self.check_not_disposed();
+
+        let id = self.new_signal_id();
 
         self.signal_values
             .write()
@@ -86,6 +251,24 @@ impl Runtime {
         self.make_signal(id)
     }
 
+    fn new_signal_id(&self) -> SignalId {
+        // deterministic ids follow the creation order, so a server and a client produce the same ids
+        if self.deterministic_ids.load(Ordering::SeqCst) {
+            SignalId::from_sequence(self.next_signal_id.fetch_add(1, Ordering::SeqCst) + 1)
+        } else {
+            SignalId::new()
+        }
+    }
+
+    pub fn create_trigger(self: Arc<Self>) -> Trigger {
+        self.check_not_disposed();
+
+        // a trigger only uses the subscribers of its id, it has no value
+        let id = self.new_signal_id();
+
+        Trigger::new(self, id)
+    }
+
     fn make_signal<T: 'static>(self: Arc<Self>, id: SignalId) -> Signal<T> {
         Signal::new(Arc::clone(&self), id)
     }
@@ -100,6 +283,10 @@ impl Runtime {
             .insert(dest);
     }
 
+    pub(super) fn is_signal_alive(&self, id: SignalId) -> bool {
+        self.signal_refs.read().unwrap().contains_key(&id)
+    }
+
     pub(super) fn get_source_id(&self, mut id: SignalId) -> SignalId {
         {
             let signal_links = self.signal_links.read().unwrap();
@@ -113,11 +300,13 @@ impl Runtime {
     }
 
     pub(super) fn create_link<T: 'static>(self: Arc<Self>, src: SignalId) -> Signal<T> {
+        self.check_not_disposed();
+
         // if linking from a link, link to the linked signal
         let src = self.get_source_id(src);
 
         // allocate new signal id
-        let dest = SignalId::new();
+        let dest = self.new_signal_id();
 
         // add link
         self.make_link(dest, src);
@@ -149,12 +338,19 @@ impl Runtime {
     }
 
     pub(super) fn clean_signal(&self, id: SignalId) {
+        // everything has already been removed by the disposal
+        if self.is_disposed() {
+            return;
+        }
+
         if self.dec_signal_ref(id) == 1 {
             self.remove_signal(id);
         }
     }
 
     pub(super) fn link_signal(&self, dest: SignalId, src: SignalId) {
+        self.check_not_disposed();
+
         // if linking from a link, link to the linked signal
         let src = self.get_source_id(src);
 
@@ -166,7 +362,11 @@ impl Runtime {
         // remove old dependencies
         if self.signal_values.read().unwrap().contains_key(&dest) {
             if let Some(value) = self.remove_signal_dependencies(dest) {
-                self.signal_values.write().unwrap().insert(src, value);
+                let replaced = self.signal_values.write().unwrap().insert(src, value);
+
+                // the replaced value is dropped outside of the lock, as it can hold signals
+                drop(replaced);
+                self.bump_version(src);
                 self.notify_subscribers(src);
             }
         }
@@ -176,6 +376,8 @@ impl Runtime {
     }
 
     pub fn create_effect(&self, f: impl Fn() + 'static) {
+        self.check_not_disposed();
+
         // allocate effect id
         let id = EffectId::new();
 
@@ -186,6 +388,218 @@ impl Runtime {
         self.run_effect(id);
     }
 
+    pub fn create_change_effect<T: 'static, C: 'static>(
+        self: &Arc<Self>,
+        signal: &Signal<T>,
+        f: impl Fn(&C) + 'static,
+    ) -> Subscription {
+        self.check_not_disposed();
+        self.check_same_runtime(&signal.runtime(), "watch the changes of");
+
+        let id = EffectId::new();
+        let signal_id = signal.signal_id();
+
+        // the effect lives until the handle is dropped, or as long as the signal it has been created for
+        let f = move |change: &dyn Any| {
+            if let Some(change) = change.downcast_ref::<C>() {
+                f(change);
+            }
+        };
+
+        self.change_effects
+            .write()
+            .unwrap()
+            .entry(signal_id)
+            .or_default()
+            .insert(id, Arc::new(f));
+
+        Subscription::for_change(Arc::downgrade(self), signal_id, id)
+    }
+
+    pub(super) fn remove_change_effect(&self, signal_id: SignalId, effect_id: EffectId) {
+        if self.is_disposed() {
+            return;
+        }
+
+        // the effect is dropped without holding the lock, as it can own signals
+        let effect = {
+            let mut change_effects = self.change_effects.write().unwrap();
+            let effects = change_effects.get_mut(&signal_id);
+            let effect = effects.and_then(|effects| effects.remove(&effect_id));
+
+            if change_effects.get(&signal_id).is_some_and(HashMap::is_empty) {
+                change_effects.remove(&signal_id);
+            }
+
+            effect
+        };
+
+        drop(effect);
+    }
+
+    /// Change effects watching the signal, captured when a change is emitted,
+    /// as the effects created before its delivery have been built from the changed value
+    pub(super) fn change_watchers(&self, signal_id: SignalId) -> Vec<(SignalId, EffectId)> {
+        // change effects can be created from any signal linked to the same value,
+        // so only the signals of the link tree of the source are looked up
+        let mut linked_ids = vec![self.get_source_id(signal_id)];
+        let mut index = 0;
+
+        {
+            let reverse_links = self.reverse_links.read().unwrap();
+
+            while let Some(id) = linked_ids.get(index).copied() {
+                linked_ids.extend(reverse_links.get(&id).into_iter().flatten().copied());
+                index += 1;
+            }
+        }
+
+        let change_effects = self.change_effects.read().unwrap();
+
+        linked_ids
+            .iter()
+            .filter_map(|id| Some((*id, change_effects.get(id)?)))
+            .flat_map(|(id, effects)| effects.keys().map(move |effect_id| (id, *effect_id)))
+            .collect()
+    }
+
+    /// Sends the change to the watchers which are still alive
+    pub(super) fn notify_change(&self, watchers: &[(SignalId, EffectId)], change: &dyn Any) {
+        let change_effects = {
+            let change_effects = self.change_effects.read().unwrap();
+
+            watchers
+                .iter()
+                .filter_map(|(id, effect_id)| change_effects.get(id)?.get(effect_id).cloned())
+                .collect::<Vec<_>>()
+        };
+
+        for effect in change_effects {
+            effect(change);
+        }
+    }
+
+    pub(super) fn create_subscription<T: Clone + 'static>(
+        self: &Arc<Self>,
+        signal_id: SignalId,
+        immediate: bool,
+        f: impl Fn(&T) + 'static,
+    ) -> Subscription {
+        self.create_subscription_with(signal_id, immediate, T::clone, move |value: T| f(&value))
+    }
+
+    /// The value is read by `read` and the lock is released before `f` runs, so `f` can update the signal
+    pub(super) fn create_subscription_with<T: 'static, V>(
+        self: &Arc<Self>,
+        signal_id: SignalId,
+        immediate: bool,
+        read: impl Fn(&T) -> V + 'static,
+        f: impl Fn(V) + 'static,
+    ) -> Subscription {
+        self.check_not_disposed();
+
+        let id = EffectId::new();
+        let runtime = Arc::downgrade(self);
+        let first_run = AtomicBool::new(true);
+
+        // only the subscribed signal is tracked, the callback runs untracked
+        let effect = move || {
+            if let Some(runtime) = runtime.upgrade() {
+                runtime.add_subscriber(signal_id);
+
+                if first_run.swap(false, Ordering::SeqCst) && !immediate {
+                    return;
+                }
+
+                let value_ref = runtime.get_value(&runtime.get_source_id(signal_id));
+                let value = runtime.untrack(|| read(value_ref.read().unwrap().downcast_ref::<T>().unwrap()));
+
+                runtime.untrack(|| f(value));
+            }
+        };
+
+        self.effects.write().unwrap().insert(id, Arc::new(effect));
+        self.run_effect(id);
+
+        Subscription::new(Arc::downgrade(self), id)
+    }
+
+    pub(super) fn remove_subscription(&self, effect_id: EffectId) {
+        if self.is_disposed() {
+            return;
+        }
+
+        let effect = self.effects.write().unwrap().remove(&effect_id);
+
+        // the subscribed signal is not removed with the subscription, so no empty subscriber set is kept
+        self.signal_subscribers.write().unwrap().retain(|_, effect_ids| {
+            effect_ids.remove(&effect_id);
+
+            !effect_ids.is_empty()
+        });
+
+        drop(effect);
+    }
+
+    pub fn untrack<R>(&self, f: impl FnOnce() -> R) -> R {
+        let prev_running_effect = self.running_effect.write().unwrap().take();
+        let result = f();
+
+        self.pop_effect(prev_running_effect);
+
+        result
+    }
+
+    pub fn provide<T: 'static>(&self, value: T) {
+        self.check_not_disposed();
+        self.stores.provide(value);
+    }
+
+    pub fn use_store<T: Clone + 'static>(&self) -> Option<T> {
+        self.check_not_disposed();
+        self.stores.get::<T>()
+    }
+
+    /// Root store scope, a level of the component tree provides its own values in a child scope
+    pub fn stores(&self) -> StoreScope {
+        self.stores.clone()
+    }
+
+    /// Effect running a future, the signals read before its first await are tracked,
+    /// and a new run aborts the future of the previous one, like the disposal of the effect does
+    pub fn create_async_effect<R, F>(self: &Arc<Self>, f: F)
+    where
+        R: Future<Output = ()> + 'static,
+        F: Fn() -> R + 'static, {
+        self.check_not_disposed();
+
+        create_async_effect(self, f);
+    }
+
+    pub fn create_effect_with_prev<R: 'static>(&self, f: impl Fn(Option<R>) -> R + 'static) {
+        let prev = Mutex::new(None);
+
+        // the previous value is taken out before running, so the lock is not held by a nested run
+        self.create_effect(move || {
+            let prev_value = prev.lock().unwrap().take();
+            let value = f(prev_value);
+
+            *prev.lock().unwrap() = Some(value);
+        });
+    }
+
+    pub(super) fn is_tracking(&self) -> bool {
+        self.running_effect.read().unwrap().is_some()
+    }
+
+    pub(super) fn has_subscribers(&self, signal_id: SignalId) -> bool {
+        self.signal_subscribers
+            .read()
+            .unwrap()
+            .get(&signal_id)
+            .is_some_and(|effect_ids| !effect_ids.is_empty())
+    }
+
     pub(super) fn add_subscriber(&self, signal_id: SignalId) -> bool {
         if let Some(effect_id) = self.running_effect.read().unwrap().clone() {
             self.signal_subscribers
@@ -298,6 +712,9 @@ impl Runtime {
             {
                 let mut signal_links = self.signal_links.write().unwrap();
 
+                // the new source is not a link anymore
+                signal_links.remove(&new_id);
+
                 for linked_id in &linked_signals {
                     signal_links.insert(*linked_id, new_id);
                 }
@@ -305,6 +722,13 @@ impl Runtime {
 
             self.signal_values.write().unwrap().insert(new_id, value);
 
+            // the version follows the value
+            let version = self.signal_versions.write().unwrap().remove(&signal_id);
+
+            if let Some(version) = version {
+                self.signal_versions.write().unwrap().insert(new_id, version);
+            }
+
             if !linked_signals.is_empty() {
                 self.reverse_links.write().unwrap().insert(new_id, linked_signals);
             }
@@ -341,9 +765,16 @@ impl Runtime {
             return;
         }
 
+        self.sent_updates.close(signal_id);
+
         // remove dependencies
         self.remove_signal_dependencies(signal_id);
 
+        // remove change effects
+        let change_effects = self.change_effects.write().unwrap().remove(&signal_id);
+
+        drop(change_effects);
+
         // remove subscribers
         let mut to_remove = HashSet::new();
         if let Some(effect_ids) = self.signal_subscribers.write().unwrap().remove(&signal_id) {
@@ -364,6 +795,10 @@ impl Runtime {
 
             if let Some(value) = removed_value {
                 returned_value = self.remove_value_links(signal_id, value);
+
+                if returned_value.is_some() {
+                    self.signal_versions.write().unwrap().remove(&signal_id);
+                }
             }
         }
 
@@ -381,7 +816,11 @@ impl Runtime {
 
     fn remove_effect(&self, effect_id: EffectId) {
         self.pending_remove.write().unwrap().replace(HashSet::new());
-        self.effects.write().unwrap().remove(&effect_id);
+
+        // the effect is dropped without holding the lock, because it can own subscriptions to remove
+        let effect = self.effects.write().unwrap().remove(&effect_id);
+
+        drop(effect);
 
         {
             let mut signal_subscribers = self.signal_subscribers.write().unwrap();
@@ -412,6 +851,80 @@ impl Runtime {
         }
     }
 
+    pub fn signal_from_future<T, F>(self: Arc<Self>, initial: T, fut: F) -> Signal<T>
+    where
+        T: 'static,
+        F: Future<Output = T> + 'static, {
+        self.signal_from_stream(initial, fut.into_stream())
+    }
+
+    pub fn signal_from_stream<T, S>(self: Arc<Self>, initial: T, stream: S) -> Signal<T>
+    where
+        T: 'static,
+        S: Stream<Item = T> + 'static, {
+        let signal = Arc::clone(&self).create_signal(initial);
+
+        self.feed_signal(&signal, stream, |target, value| target.set(value));
+
+        signal
+    }
+
+    pub(super) fn feed_signal<T, U>(
+        self: &Arc<Self>,
+        signal: &Signal<T>,
+        stream: impl Stream<Item = U> + 'static,
+        apply: impl Fn(&WeakSignal<T>, U) -> bool + 'static,
+    ) {
+        let target = signal.downgrade();
+        let (drop_tx, drop_rx) = oneshot::channel::<()>();
+
+        // the sender is dropped with the subscription effect, when the signal is dropped
+        let subscription = self.create_subscription_with(
+            signal.signal_id(),
+            false,
+            |_: &T| (),
+            move |()| {
+                let _ = &drop_tx;
+            },
+        );
+
+        let feed = async move {
+            let mut stream = pin!(stream);
+
+            while let Some(value) = stream.next().await {
+                if !apply(&target, value) {
+                    break;
+                }
+            }
+        };
+
+        self.spawn(LocalFuture::new(AssertUnwindSafe(async move {
+            let _subscription = subscription;
+
+            select(pin!(feed), drop_rx).await;
+        })));
+    }
+
+    pub(super) fn update_sender<T: 'static>(self: &Arc<Self>, signal_id: SignalId) -> SignalSender<T> {
+        self.check_not_disposed();
+        self.sent_updates.sender(self, signal_id)
+    }
+
+    pub fn bridge<T: Clone + 'static>(src: &Signal<T>, dst_runtime: &Arc<Runtime>) -> Signal<T> {
+        Self::bridge_with(src, dst_runtime, BridgeDirection::OneWay)
+    }
+
+    pub fn bridge_with<T: Clone + 'static>(
+        src: &Signal<T>,
+        dst_runtime: &Arc<Runtime>,
+        direction: BridgeDirection,
+    ) -> Signal<T> {
+        src.runtime().check_not_disposed();
+        dst_runtime.check_not_disposed();
+
+        create_bridge(src, dst_runtime, direction)
+    }
+
     pub fn create_action<I, O, F, R>(self: Arc<Self>, f: F) -> Action<I, O>
     where
         O: UnwindSafe + 'static,
@@ -424,6 +937,8 @@ impl Runtime {
     where
         T: PartialEq + 'static,
         F: Fn(Option<&T>) -> T + 'static, {
+        self.check_not_disposed();
+
         // allocate effect id
         let effect_id = EffectId::new();
 
@@ -467,10 +982,47 @@ impl Runtime {
         res
     }
 
+    /// Memo computed by a future from the last ready value, the signals read before its first await are tracked,
+    /// and the result of a computation is dropped when the dependencies change in the meantime
+    pub fn create_async_memo<T, E, F, R>(self: Arc<Self>, f: F) -> Signal<AsyncState<T, E>>
+    where
+        T: Clone + PartialEq + 'static,
+        E: PartialEq + 'static,
+        R: Future<Output = Result<T, E>> + 'static,
+        F: Fn(Option<T>) -> R + 'static, {
+        self.check_not_disposed();
+
+        create_async_memo(self, f)
+    }
+
+    pub fn create_signal_vec<T: 'static>(self: Arc<Self>, values: Vec<T>) -> SignalVec<T> {
+        self.check_not_disposed();
+
+        SignalVec::new(self, values)
+    }
+
+    pub fn create_reactive_map<K, V>(self: Arc<Self>, values: impl IntoIterator<Item = (K, V)>) -> ReactiveMap<K, V>
+    where
+        K: Clone + Eq + Hash + 'static,
+        V: 'static, {
+        self.check_not_disposed();
+
+        ReactiveMap::new(self, values)
+    }
+
+    pub fn create_selector<K: Clone + Eq + Hash + 'static>(self: Arc<Self>, source: Signal<K>) -> Selector<K> {
+        self.check_not_disposed();
+        self.check_same_runtime(&source.runtime(), "select");
+
+        Selector::new(self, source)
+    }
+
     pub fn create_keyed_signal<C, V>(self: Arc<Self>, c: Signal<C>, key: &str) -> Signal<Option<V>>
     where
         V: Clone + PartialEq + 'static,
         C: KeyedCollection<Value = V> + 'static, {
+        self.check_same_runtime(&c.runtime(), "create a keyed signal with");
+
         let key = key.to_string();
 
         self.create_memo(move |_| c.with(|c| c.keyed_get(&key).cloned()))
@@ -480,6 +1032,8 @@ impl Runtime {
     where
         V: ToString + 'static,
         C: KeyedCollection<Value = V> + 'static, {
+        self.check_same_runtime(&c.runtime(), "create a keyed signal with");
+
         let key = key.to_string();
 
         self.create_memo(move |_| c.with(|c| c.keyed_get(&key).map(|v| v.to_string())))
@@ -488,6 +1042,93 @@ impl Runtime {
     pub fn create_css_classes(self: Arc<Self>) -> CssClasses {
         CssClasses::new(Arc::clone(&self))
     }
+
+    #[cfg(feature = "serde")]
+    pub fn current() -> Option<Arc<Self>> {
+        current_runtime()
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn with_current<R>(self: &Arc<Self>, f: impl FnOnce() -> R) -> R {
+        let _guard = CurrentRuntimeGuard::enter(Arc::clone(self));
+
+        f()
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn with_retained_states(self: Arc<Runtime>) -> Arc<Self> {
+        self.retain_states.store(true, Ordering::SeqCst);
+        self
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn register_state<T: Serialize + DeserializeOwned + 'static>(self: &Arc<Self>, key: &str, signal: &Signal<T>) {
+        self.register_state_with(key, signal, JsonStateSerializer);
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn register_state_with<T: 'static>(
+        self: &Arc<Self>,
+        key: &str,
+        signal: &Signal<T>,
+        serializer: impl StateSerializer<T> + 'static,
+    ) {
+        self.check_same_runtime(&signal.runtime(), "register the state of");
+
+        let retained = self.retain_states.load(Ordering::SeqCst);
+
+        if self
+            .states
+            .register(self, key, signal.signal_id(), serializer, retained)
+        {
+            self.notify_restored(vec![signal.signal_id()]);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn unregister_state(&self, key: &str) -> bool {
+        self.states.unregister(self, key)
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn take_snapshot(&self) -> Result<StateSnapshot, serde_json::Error> {
+        self.check_not_disposed();
+        self.states.snapshot(self)
+    }
+
+    /// Snapshot of the values which can be serialized, along with the errors of the others, keyed by state
+    #[cfg(feature = "serde")]
+    pub fn take_partial_snapshot(&self) -> (StateSnapshot, Vec<(String, serde_json::Error)>) {
+        self.check_not_disposed();
+        self.states.partial_snapshot(self)
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn restore_snapshot(self: &Arc<Self>, snapshot: &StateSnapshot) -> Result<(), serde_json::Error> {
+        self.check_not_disposed();
+        self.notify_restored(self.states.restore(self, snapshot)?);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn hydrate_state(self: &Arc<Self>, snapshot: StateSnapshot) {
+        self.check_not_disposed();
+        self.notify_restored(self.states.hydrate(self, snapshot));
+    }
+
+    #[cfg(feature = "serde")]
+    fn notify_restored(self: &Arc<Self>, ids: Vec<SignalId>) {
+        for id in ids {
+            let runtime = Arc::clone(self);
+
+            self.bump_version(id);
+
+            self.defer(move || {
+                runtime.notify_subscribers(id);
+            });
+        }
+    }
 }
 
 impl Eq for Runtime {}
@@ -571,7 +1212,15 @@ impl Debug for Runtime {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::signal::tests::create_runtime;
+    use crate::{
+        signal::{
+            tests::{create_queued_runtime, create_runtime, QueuedRunner},
+            Change,
+        },
+        spawner::generators::FuturesSpawner,
+    };
+
+    use futures::{channel::mpsc, stream};
     use std::fmt::{Debug, Display};
 
     #[test]
@@ -607,6 +1256,291 @@ mod tests {
         assert_eq!(rt.effects.read().unwrap().len(), 0, "effects should be empty");
     }
 
+    #[test]
+    fn test_runtime_id() {
+        let rt = create_runtime();
+        let other = create_runtime();
+
+        assert!(
+            rt.id().to_string().starts_with("runtime:id:"),
+            "runtime id should be formatted"
+        );
+        assert_eq!(rt.id(), Arc::clone(&rt).id(), "runtime id should be stable");
+        assert_ne!(rt.id(), other.id(), "each runtime should have its own id");
+        assert_ne!(rt, other, "runtimes with different ids should not be equal");
+        assert_eq!(rt, Arc::clone(&rt), "a runtime should be equal to itself");
+    }
+
+    #[test]
+    fn test_deterministic_ids() {
+        let ids = || {
+            let rt = create_runtime().with_deterministic_ids();
+            let signal = Arc::clone(&rt).create_signal(42);
+            let link = signal.create_link();
+
+            (signal.id(), link.id())
+        };
+
+        assert_eq!(ids(), ids(), "ids should only depend on the creation order");
+        assert_eq!(
+            ids().0,
+            "signal:id:00000000-0000-0000-0000-000000000001",
+            "ids should be numbered from 1"
+        );
+    }
+
+    #[test]
+    fn test_deterministic_ids_across_runtimes() {
+        let rt = create_runtime().with_deterministic_ids();
+        let other = create_runtime().with_deterministic_ids();
+        let signal = Arc::clone(&rt).create_signal(42);
+        let other_signal = Arc::clone(&other).create_signal(42);
+
+        assert_eq!(
+            signal.id(),
+            other_signal.id(),
+            "both runtimes should number their ids the same way"
+        );
+        assert_ne!(
+            signal, other_signal,
+            "signals of different runtimes should not be equal"
+        );
+        assert_ne!(
+            rt.create_trigger(),
+            other.create_trigger(),
+            "triggers of different runtimes should not be equal"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Cross-runtime usage: cannot link")]
+    fn test_cross_runtime_link() {
+        let signal = create_runtime().create_signal(42);
+        let other = create_runtime().create_signal(0);
+
+        other.link_to(&signal);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cross-runtime usage: cannot combine")]
+    fn test_cross_runtime_with_another() {
+        let signal = create_runtime().create_signal(1);
+        let other = create_runtime().create_signal(2);
+
+        signal.with_another(other, |a, b| a + b);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cross-runtime usage: cannot create a keyed signal")]
+    fn test_cross_runtime_keyed_signal() {
+        let collection = create_runtime().create_signal(vec![1, 2, 3]);
+
+        create_runtime().create_keyed_signal(collection, "0");
+    }
+
+    #[test]
+    fn test_dispose() {
+        let rt = create_runtime();
+        let signal = Arc::clone(&rt).create_signal(42);
+        let count = Arc::new(AtomicUsize::new(0));
+
+        {
+            let signal = signal.clone();
+            let count = Arc::clone(&count);
+
+            rt.create_effect(move || {
+                count.fetch_add(signal.get(), Ordering::SeqCst);
+            });
+        }
+
+        assert!(!rt.is_disposed(), "runtime should not be disposed before disposal");
+        rt.dispose();
+
+        assert!(rt.is_disposed(), "runtime should be disposed after disposal");
+        assert!(rt.effects.read().unwrap().is_empty(), "effects should be dropped");
+        assert!(rt.signal_values.read().unwrap().is_empty(), "values should be dropped");
+        assert!(
+            rt.signal_refs.read().unwrap().is_empty(),
+            "signal refs should be dropped"
+        );
+        assert!(
+            rt.signal_subscribers.read().unwrap().is_empty(),
+            "subscribers should be dropped"
+        );
+
+        // disposing twice and dropping signals after disposal should be harmless
+        rt.dispose();
+        drop(signal);
+
+        assert_eq!(count.load(Ordering::SeqCst), 42, "effect should have run once");
+    }
+
+    #[test]
+    #[should_panic(expected = "Disposed runtime")]
+    fn test_use_signal_after_dispose() {
+        let rt = create_runtime();
+        let signal = Arc::clone(&rt).create_signal(42);
+
+        rt.dispose();
+        signal.get();
+    }
+
+    #[test]
+    #[should_panic(expected = "Disposed runtime")]
+    fn test_create_signal_after_dispose() {
+        let rt = create_runtime();
+
+        rt.dispose();
+        Arc::clone(&rt).create_signal(42);
+    }
+
+    #[test]
+    fn test_dispose_cancels_deferred_jobs() {
+        let runner = QueuedRunner::default();
+        let rt = Runtime::new()
+            .with_spawn_generator(FuturesSpawner::new())
+            .with_defer_runner(runner.clone());
+
+        let signal = Arc::clone(&rt).create_signal(1);
+        let count = Arc::new(AtomicUsize::new(0));
+
+        {
+            let signal = signal.clone();
+            let count = Arc::clone(&count);
+
+            rt.create_effect(move || {
+                count.fetch_add(signal.get(), Ordering::SeqCst);
+            });
+        }
+
+        signal.set(10);
+        signal.set(100);
+        rt.dispose();
+        runner.run_all();
+
+        assert_eq!(
+            count.load(Ordering::SeqCst),
+            1,
+            "pending deferred jobs should not run after disposal"
+        );
+    }
+
+    #[test]
+    fn test_dispose_cancels_spawned_futures() {
+        let (rt, spawner) = create_queued_runtime();
+
+        let (sender, receiver) = oneshot::channel::<()>();
+        let receiver = AssertUnwindSafe(receiver);
+        let done = Arc::new(AtomicBool::new(false));
+
+        {
+            let done = Arc::clone(&done);
+
+            rt.spawn(async move {
+                let _ = receiver.await;
+
+                done.store(true, Ordering::SeqCst);
+            });
+        }
+
+        assert_eq!(rt.spawned_tasks.read().unwrap().len(), 1, "task should be registered");
+
+        rt.dispose();
+        let _ = sender.send(());
+        spawner.run_all();
+
+        assert!(!done.load(Ordering::SeqCst), "spawned future should be aborted");
+        assert!(
+            rt.spawned_tasks.read().unwrap().is_empty(),
+            "task should be unregistered"
+        );
+    }
+
+    #[test]
+    fn test_spawned_future_is_unregistered_when_done() {
+        let rt = create_runtime();
+        let done = Arc::new(AtomicBool::new(false));
+
+        {
+            let done = Arc::clone(&done);
+
+            rt.spawn(async move {
+                done.store(true, Ordering::SeqCst);
+            });
+        }
+
+        assert!(done.load(Ordering::SeqCst), "spawned future should be run");
+        assert!(
+            rt.spawned_tasks.read().unwrap().is_empty(),
+            "task should be unregistered"
+        );
+    }
+
+    #[test]
+    fn test_signal_from_future() {
+        let rt = create_runtime();
+        let signal = Arc::clone(&rt).signal_from_future(0, async { 42 });
+
+        assert_eq!(signal.get(), 42, "signal should hold the output of the future");
+    }
+
+    #[test]
+    fn test_signal_from_stream() {
+        let rt = create_runtime();
+        let (sender, receiver) = mpsc::unbounded();
+
+        for v in 1..=3 {
+            sender.unbounded_send(v).unwrap();
+        }
+
+        drop(sender);
+
+        let signal = Arc::clone(&rt).signal_from_stream(0, receiver);
+
+        assert_eq!(signal.get(), 3, "signal should hold the last value of the stream");
+    }
+
+    #[test]
+    fn test_signal_from_stream_stops_when_signal_is_dropped() {
+        let (rt, spawner) = create_queued_runtime();
+
+        let signal = Arc::clone(&rt).signal_from_stream(0, stream::pending::<i32>());
+
+        drop(signal);
+        spawner.run_all();
+
+        assert!(
+            rt.spawned_tasks.read().unwrap().is_empty(),
+            "feeding task should be stopped"
+        );
+    }
+
+    #[test]
+    fn test_bridge_is_removed_when_a_side_is_dropped() {
+        let rt = create_runtime();
+        let other = create_runtime();
+        let signal = Arc::clone(&rt).create_signal(1);
+        let mirror = Runtime::bridge_with(&signal, &other, BridgeDirection::TwoWay);
+
+        drop(mirror);
+        signal.set(2);
+
+        assert!(
+            rt.signal_subscribers.read().unwrap().is_empty(),
+            "source subscription should be removed"
+        );
+
+        let mirror = Runtime::bridge_with(&signal, &other, BridgeDirection::TwoWay);
+
+        drop(signal);
+        mirror.set(3);
+
+        assert!(
+            other.signal_subscribers.read().unwrap().is_empty(),
+            "mirror subscription should be removed"
+        );
+    }
+
     #[test]
     fn test_inc_signal_ref() {
         let rt = create_runtime();
@@ -1359,6 +2293,172 @@ mod tests {
         assert_eq!(signal.get(), Some(String::from("42")), "signal value should be updated");
     }
 
+    #[test]
+    fn test_create_change_effect() {
+        let rt = create_runtime();
+        let signal = Arc::clone(&rt).create_signal(vec![1, 2]);
+        let link = signal.create_link();
+        let changes = Arc::new(Mutex::new(vec![]));
+        let runs = Arc::new(AtomicUsize::new(0));
+
+        let _subscription = {
+            let changes = Arc::clone(&changes);
+
+            rt.create_change_effect(&link, move |change: &Change<usize>| {
+                changes.lock().unwrap().push(change.clone());
+            })
+        };
+
+        {
+            let signal = signal.clone();
+            let runs = Arc::clone(&runs);
+
+            rt.create_effect(move || {
+                signal.with(|_| runs.fetch_add(1, Ordering::SeqCst));
+            });
+        }
+
+        signal.update_with_change(|v| {
+            v.push(3);
+
+            Change::Inserted(2_usize)
+        });
+
+        signal.update_with_change(|v| {
+            v.remove(0);
+
+            Change::Removed(0_usize)
+        });
+
+        signal.update_with_change(|_| "ignored");
+        signal.set(vec![]);
+
+        assert_eq!(
+            *changes.lock().unwrap(),
+            vec![Change::Inserted(2), Change::Removed(0)],
+            "change effect should receive the payloads of its type"
+        );
+
+        assert_eq!(
+            runs.load(Ordering::SeqCst),
+            5,
+            "plain effects should be run for every update"
+        );
+
+        drop(link);
+        signal.update_with_change(|_| Change::<usize>::Replaced);
+
+        assert_eq!(
+            changes.lock().unwrap().len(),
+            2,
+            "change effect should be removed with its signal"
+        );
+    }
+
+    #[test]
+    fn test_change_effect_removed_with_its_handle() {
+        let rt = create_runtime();
+        let signal = Arc::clone(&rt).create_signal(0);
+        let changes = Arc::new(AtomicUsize::new(0));
+
+        let subscription = {
+            let changes = Arc::clone(&changes);
+
+            rt.create_change_effect(&signal, move |_: &Change<usize>| {
+                changes.fetch_add(1, Ordering::SeqCst);
+            })
+        };
+
+        signal.update_with_change(|_| Change::<usize>::Replaced);
+        drop(subscription);
+        signal.update_with_change(|_| Change::<usize>::Replaced);
+
+        assert_eq!(
+            changes.load(Ordering::SeqCst),
+            1,
+            "change effect should be removed with its handle"
+        );
+        assert!(
+            rt.change_effects.read().unwrap().is_empty(),
+            "no empty change effect set should be kept"
+        );
+    }
+
+    #[test]
+    fn test_create_effect_with_prev() {
+        let rt = create_runtime();
+        let signal = Arc::clone(&rt).create_signal(1);
+        let history = Arc::new(Mutex::new(vec![]));
+
+        {
+            let signal = signal.clone();
+            let history = Arc::clone(&history);
+
+            rt.create_effect_with_prev(move |prev: Option<i32>| {
+                let value = signal.get();
+
+                history.lock().unwrap().push((prev, value));
+
+                value
+            });
+        }
+
+        signal.set(2);
+        signal.set(3);
+
+        assert_eq!(
+            *history.lock().unwrap(),
+            vec![(None, 1), (Some(1), 2), (Some(2), 3)],
+            "each run should receive the value returned by the previous run"
+        );
+    }
+
+    #[test]
+    fn test_provide_store() {
+        #[derive(Clone)]
+        struct Theme(Signal<String>);
+
+        let rt = create_runtime();
+
+        assert!(rt.use_store::<Theme>().is_none(), "no store should be provided yet");
+
+        rt.provide(Theme(Arc::clone(&rt).create_signal("light".to_string())));
+        rt.provide(42_u32);
+
+        let theme = rt.use_store::<Theme>().unwrap();
+        theme.0.set("dark".to_string());
+
+        assert_eq!(
+            rt.use_store::<Theme>().unwrap().0.get(),
+            "dark",
+            "store should share the same signals"
+        );
+        assert_eq!(rt.use_store::<u32>(), Some(42), "stores should be keyed by type");
+
+        rt.provide(7_u32);
+
+        assert_eq!(
+            rt.use_store::<u32>(),
+            Some(7),
+            "providing again should replace the store"
+        );
+    }
+
+    #[test]
+    fn test_store_dropped_on_dispose() {
+        let rt = create_runtime();
+        let value = Arc::new(());
+
+        rt.provide(Arc::clone(&value));
+        rt.dispose();
+
+        assert_eq!(
+            Arc::strong_count(&value),
+            1,
+            "stored values should be dropped on dispose"
+        );
+    }
+
     #[test]
     fn test_create_memo() {
         let rt = create_runtime();
@@ -0,0 +1,259 @@
+use super::{Runtime, Signal, Subscription, Trigger};
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    mem::replace,
+    rc::Rc,
+    sync::{Arc, Mutex},
+};
+
+struct Triggers<K> {
+    by_key:     HashMap<K, Trigger>,
+    pruned_len: usize,
+}
+
+struct SelectorState<K> {
+    current:  Mutex<K>,
+    triggers: Mutex<Triggers<K>>,
+}
+
+/// Tells whether a key is the selected one, an effect checking a key is only
+/// re-run when that key becomes selected or unselected
+#[derive(Clone)]
+pub struct Selector<K: 'static> {
+    runtime: Arc<Runtime>,
+    source:  Signal<K>,
+    state:   Arc<SelectorState<K>>,
+
+    // the source stays subscribed as long as a clone of the selector lives
+    _subscription: Rc<Subscription>,
+}
+
+impl<K: Clone + Eq + Hash + 'static> Selector<K> {
+    pub(super) fn new(runtime: Arc<Runtime>, source: Signal<K>) -> Self {
+        let state = Arc::new(SelectorState {
+            current:  Mutex::new(source.with(K::clone)),
+            triggers: Mutex::new(Triggers {
+                by_key:     HashMap::new(),
+                pruned_len: 0,
+            }),
+        });
+
+        let subscription = {
+            let state = Arc::clone(&state);
+            let weak_runtime = Arc::downgrade(&runtime);
+
+            runtime.create_subscription(source.signal_id(), false, move |value: &K| {
+                let prev = replace(&mut *state.current.lock().unwrap(), value.clone());
+
+                if prev == *value {
+                    return;
+                }
+
+                let Some(runtime) = weak_runtime.upgrade() else {
+                    return;
+                };
+
+                // only the previous and the new selected keys are notified, or removed when nothing subscribes them,
+                // and the lock is not held while the effects run or the triggers are dropped
+                let (triggers, pruned) = {
+                    let mut triggers = state.triggers.lock().unwrap();
+                    let mut pruned = vec![];
+                    let mut alive = vec![];
+
+                    for key in [&prev, value] {
+                        match triggers.by_key.get(key) {
+                            Some(trigger) if runtime.has_subscribers(trigger.signal_id()) => {
+                                alive.push(trigger.clone());
+                            }
+                            Some(_) => pruned.extend(triggers.by_key.remove(key)),
+                            None => {}
+                        }
+                    }
+
+                    (alive, pruned)
+                };
+
+                drop(pruned);
+
+                for trigger in triggers {
+                    trigger.notify();
+                }
+            })
+        };
+
+        Self {
+            runtime,
+            source,
+            state,
+            _subscription: Rc::new(subscription),
+        }
+    }
+
+    pub fn source(&self) -> Signal<K> {
+        self.source.clone()
+    }
+
+    pub fn is(&self, key: &K) -> bool {
+        // a trigger is only needed to re-run the running effect when the selection of the key flips
+        if self.runtime.is_tracking() {
+            let (trigger_id, pruned) = {
+                let mut triggers = self.state.triggers.lock().unwrap();
+                let pruned = match triggers.by_key.contains_key(key) {
+                    true => vec![],
+                    false => self.prune(&mut triggers),
+                };
+                let trigger_id = triggers
+                    .by_key
+                    .entry(key.clone())
+                    .or_insert_with(|| Arc::clone(&self.runtime).create_trigger())
+                    .signal_id();
+
+                (trigger_id, pruned)
+            };
+
+            drop(pruned);
+
+            // the trigger is owned by the selector, so the tracking does not go through the trigger handle
+            self.runtime.add_subscriber(trigger_id);
+        }
+
+        *self.state.current.lock().unwrap() == *key
+    }
+
+    // the triggers of the disposed effects are removed once their number has doubled, so the pruning is amortized
+    fn prune(&self, triggers: &mut Triggers<K>) -> Vec<Trigger> {
+        if triggers.by_key.len() < (triggers.pruned_len * 2).max(16) {
+            return vec![];
+        }
+
+        let keys = triggers
+            .by_key
+            .iter()
+            .filter(|(_, trigger)| !self.runtime.has_subscribers(trigger.signal_id()))
+            .map(|(key, _)| key.clone())
+            .collect::<Vec<_>>();
+        let pruned = keys.iter().filter_map(|key| triggers.by_key.remove(key)).collect();
+
+        triggers.pruned_len = triggers.by_key.len();
+        pruned
+    }
+}
+
+// no-coverage:start
+#[cfg(test)]
+mod tests {
+    use crate::signal::tests::create_runtime;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_selector() {
+        let rt = create_runtime();
+        let selected = Arc::clone(&rt).create_signal(1);
+        let selector = Arc::clone(&rt).create_selector(selected.clone());
+        let rows = (0..100)
+            .map(|key| {
+                let row = Arc::clone(&rt).create_signal((false, 0));
+                let selector = selector.clone();
+                let target = row.clone();
+
+                rt.create_effect(move || {
+                    let is_selected = selector.is(&key);
+
+                    target.untracked_update(|(selected, runs)| {
+                        *selected = is_selected;
+                        *runs += 1;
+                    });
+                });
+
+                row
+            })
+            .collect::<Vec<_>>();
+
+        assert!(rows[1].get().0, "initially selected row should be selected");
+        assert!(!rows[2].get().0, "other rows should not be selected");
+
+        selected.set(2);
+
+        assert!(!rows[1].get().0, "previous row should be unselected");
+        assert!(rows[2].get().0, "new row should be selected");
+        assert_eq!(
+            rows.iter().map(|row| row.get().1).sum::<usize>(),
+            102,
+            "only the rows whose selection flipped should be re-run"
+        );
+
+        selected.set(2);
+
+        assert_eq!(
+            rows.iter().map(|row| row.get().1).sum::<usize>(),
+            102,
+            "setting the same selection should not re-run any row"
+        );
+    }
+
+    #[test]
+    fn test_selector_outside_effect() {
+        let rt = create_runtime();
+        let selected = Arc::clone(&rt).create_signal("a");
+        let selector = Arc::clone(&rt).create_selector(selected.clone());
+
+        assert!(selector.is(&"a"), "selected key should be selected");
+        assert!(!selector.is(&"b"), "other key should not be selected");
+
+        selected.set("b");
+
+        assert!(selector.is(&"b"), "selection should follow the source");
+        assert_eq!(selector.source().get(), "b", "selector should expose its source");
+        assert_eq!(
+            selector.state.triggers.lock().unwrap().by_key.len(),
+            0,
+            "keys checked outside of an effect should not get a trigger"
+        );
+    }
+
+    #[test]
+    fn test_selector_prunes_triggers() {
+        let rt = create_runtime();
+        let selected = Arc::clone(&rt).create_signal(0);
+        let selector = Arc::clone(&rt).create_selector(selected.clone());
+        let rows = (0..40)
+            .map(|key| {
+                let alive = Arc::clone(&rt).create_signal(());
+                let selector = selector.clone();
+                let target = alive.clone();
+
+                rt.create_effect(move || {
+                    target.with(|_| ());
+                    selector.is(&key);
+                });
+
+                alive
+            })
+            .collect::<Vec<_>>();
+
+        // dropping the row signals disposes the row effects
+        drop(rows);
+        selected.set(1);
+
+        assert_eq!(
+            selector.state.triggers.lock().unwrap().by_key.len(),
+            38,
+            "triggers of the notified keys should be removed without subscribers"
+        );
+
+        let selector_in_effect = selector.clone();
+        rt.create_effect(move || {
+            for key in 100..130 {
+                selector_in_effect.is(&key);
+            }
+        });
+
+        assert_eq!(
+            selector.state.triggers.lock().unwrap().by_key.len(),
+            30,
+            "triggers without subscribers should be pruned when new keys are checked"
+        );
+    }
+}
+// no-coverage:stop
@@ -0,0 +1,255 @@
+use super::{id::SignalId, Runtime, WeakSignal};
+use crate::spawner::LocalFuture;
+use futures::{
+    channel::mpsc::{self, UnboundedReceiver, UnboundedSender},
+    StreamExt,
+};
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    sync::{Arc, Mutex},
+    collections::HashMap,
+    panic::AssertUnwindSafe,
+    marker::PhantomData,
+    mem::take,
+};
+
+type SentUpdate = Box<dyn FnOnce(&Arc<Runtime>) + Send>;
+
+/// Channel shared by the senders of a runtime, open while a sender is alive
+#[derive(Default)]
+struct Channel {
+    sender:  Option<UnboundedSender<SentUpdate>>,
+    senders: usize,
+}
+
+/// Updates sent to the signals of a runtime, applied in batches by its defer runner
+#[derive(Default)]
+pub(super) struct UpdateInbox {
+    channel: Arc<Mutex<Channel>>,
+    closed:  Mutex<HashMap<SignalId, Arc<AtomicBool>>>,
+}
+
+impl UpdateInbox {
+    pub(super) fn sender<T: 'static>(&self, runtime: &Arc<Runtime>, id: SignalId) -> SignalSender<T> {
+        let (sender, receiver) = {
+            let mut channel = self.channel.lock().unwrap();
+            let receiver = match channel.sender {
+                Some(_) => None,
+                None => {
+                    let (sender, receiver) = mpsc::unbounded();
+
+                    channel.sender = Some(sender);
+                    Some(receiver)
+                }
+            };
+
+            channel.senders += 1;
+            (channel.sender.clone().unwrap(), receiver)
+        };
+
+        // a single task per runtime receives the updates, until all the senders are dropped
+        if let Some(receiver) = receiver {
+            spawn_receiver(runtime, receiver);
+        }
+
+        SignalSender {
+            channel: Arc::clone(&self.channel),
+            closed: Arc::clone(self.closed.lock().unwrap().entry(id).or_default()),
+            sender,
+            id,
+            ty: PhantomData,
+        }
+    }
+
+    pub(super) fn close(&self, id: SignalId) {
+        if let Some(closed) = self.closed.lock().unwrap().remove(&id) {
+            closed.store(true, Ordering::SeqCst);
+        }
+    }
+
+    pub(super) fn close_all(&self) {
+        for (_, closed) in self.closed.lock().unwrap().drain() {
+            closed.store(true, Ordering::SeqCst);
+        }
+    }
+}
+
+fn spawn_receiver(runtime: &Arc<Runtime>, mut receiver: UnboundedReceiver<SentUpdate>) {
+    let weak_runtime = Arc::downgrade(runtime);
+    let pending = Arc::new(Mutex::new(Vec::<SentUpdate>::new()));
+
+    runtime.spawn(LocalFuture::new(AssertUnwindSafe(async move {
+        while let Some(update) = receiver.next().await {
+            let Some(runtime) = weak_runtime.upgrade() else {
+                break;
+            };
+
+            let is_first = {
+                let mut pending = pending.lock().unwrap();
+
+                pending.push(update);
+                pending.len() == 1
+            };
+
+            // the updates are applied on the thread of the defer runner, by a job draining them all
+            if is_first {
+                let job = AssertUnwindSafe((weak_runtime.clone(), Arc::clone(&pending)));
+
+                runtime.defer(move || {
+                    let (weak_runtime, pending) = &*job;
+                    let updates = take(&mut *pending.lock().unwrap());
+
+                    if let Some(runtime) = weak_runtime.upgrade() {
+                        for update in updates {
+                            update(&runtime);
+                        }
+                    }
+                });
+            }
+        }
+    })));
+}
+
+/// Thread-safe handle sending updates to a signal, which are applied by the defer runner of its runtime
+pub struct SignalSender<T: 'static> {
+    channel: Arc<Mutex<Channel>>,
+    closed:  Arc<AtomicBool>,
+    sender:  UnboundedSender<SentUpdate>,
+    id:      SignalId,
+    ty:      PhantomData<fn(T)>,
+}
+
+impl<T: 'static> SignalSender<T> {
+    pub fn update(&self, f: impl FnOnce(&mut T) + Send + 'static) -> bool {
+        if self.is_closed() {
+            return false;
+        }
+
+        let id = self.id;
+
+        self.sender
+            .unbounded_send(Box::new(move |runtime| {
+                WeakSignal::<T>::new(runtime, id).update(f);
+            }))
+            .is_ok()
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::SeqCst) || self.sender.is_closed()
+    }
+}
+
+impl<T: Send + 'static> SignalSender<T> {
+    pub fn set(&self, value: T) -> bool {
+        self.update(move |v| *v = value)
+    }
+}
+
+impl<T: 'static> Clone for SignalSender<T> {
+    fn clone(&self) -> Self {
+        self.channel.lock().unwrap().senders += 1;
+
+        Self {
+            channel: Arc::clone(&self.channel),
+            closed:  Arc::clone(&self.closed),
+            sender:  self.sender.clone(),
+            id:      self.id,
+            ty:      PhantomData,
+        }
+    }
+}
+
+impl<T: 'static> Drop for SignalSender<T> {
+    fn drop(&mut self) {
+        let mut channel = self.channel.lock().unwrap();
+
+        // the channel is closed with the last sender, which ends the receiving task
+        channel.senders -= 1;
+        if channel.senders == 0 {
+            channel.sender.take();
+        }
+    }
+}
+
+// no-coverage:start
+#[cfg(test)]
+mod tests {
+    use crate::signal::tests::create_queued_runtime;
+
+    use std::{sync::Arc, thread};
+
+    fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+    #[test]
+    fn test_signal_sender() {
+        let (rt, spawner) = create_queued_runtime();
+
+        let signal = Arc::clone(&rt).create_signal(1);
+        let other = Arc::clone(&rt).create_signal("a");
+        let sender = signal.sender();
+        let other_sender = other.sender();
+
+        assert_send_sync(&sender);
+
+        {
+            let sender = sender.clone();
+
+            thread::spawn(move || {
+                sender.set(10);
+                other_sender.set("b");
+                sender.update(|v| *v += 1);
+            })
+            .join()
+            .unwrap();
+        }
+
+        drop(sender);
+        spawner.run_all();
+
+        assert_eq!(signal.get(), 11, "updates should be applied in order");
+        assert_eq!(other.get(), "b", "updates of another signal should be applied");
+    }
+
+    #[test]
+    fn test_signal_sender_is_closed_when_signal_is_dropped() {
+        let (rt, _) = create_queued_runtime();
+
+        let signal = Arc::clone(&rt).create_signal(1);
+        let sender = signal.sender();
+
+        drop(signal);
+
+        assert!(sender.is_closed(), "sender should be closed");
+        assert!(!sender.set(2), "sending to a dropped signal should fail");
+    }
+
+    #[cfg(feature = "task-spawner")]
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_signal_sender_from_task() {
+        use crate::{signal::Runtime, spawner::generators::TaskSpawner, defer::runners::RunnerForTests};
+        use std::time::Duration;
+        use tokio::{
+            task::{spawn_blocking, yield_now},
+            time::timeout,
+        };
+
+        let rt = Runtime::new()
+            .with_spawn_generator(TaskSpawner::new())
+            .with_defer_runner(RunnerForTests::new());
+
+        let signal = Arc::clone(&rt).create_signal(0);
+        let sender = signal.sender();
+
+        spawn_blocking(move || sender.set(42)).await.unwrap();
+
+        // an update which is never applied fails the test instead of hanging it
+        timeout(Duration::from_secs(5), async {
+            while signal.get() != 42 {
+                yield_now().await;
+            }
+        })
+        .await
+        .expect("sent value should be applied");
+    }
+}
+// no-coverage:stop
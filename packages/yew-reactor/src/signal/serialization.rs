@@ -0,0 +1,173 @@
+use super::{Runtime, Signal};
+use serde::{
+    de::{DeserializeSeed, Error as DeError},
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+
+use std::{cell::RefCell, marker::PhantomData, sync::Arc};
+
+thread_local! {
+    static CURRENT_RUNTIMES: RefCell<Vec<Arc<Runtime>>> = const { RefCell::new(Vec::new()) };
+}
+
+pub(super) struct CurrentRuntimeGuard;
+
+impl CurrentRuntimeGuard {
+    pub(super) fn enter(runtime: Arc<Runtime>) -> Self {
+        CURRENT_RUNTIMES.with(|runtimes| runtimes.borrow_mut().push(runtime));
+
+        Self
+    }
+}
+
+impl Drop for CurrentRuntimeGuard {
+    fn drop(&mut self) {
+        CURRENT_RUNTIMES.with(|runtimes| runtimes.borrow_mut().pop());
+    }
+}
+
+pub(super) fn current_runtime() -> Option<Arc<Runtime>> {
+    CURRENT_RUNTIMES.with(|runtimes| runtimes.borrow().last().cloned())
+}
+
+pub(crate) fn current_runtime_for<'de, D: Deserializer<'de>>(kind: &str) -> Result<Arc<Runtime>, D::Error> {
+    current_runtime().ok_or_else(|| {
+        D::Error::custom(format!(
+            "no current runtime to deserialize {kind}, use `Runtime::with_current` or a runtime-aware seed"
+        ))
+    })
+}
+
+/// Deserializes a signal in a given runtime
+pub struct SignalSeed<T: 'static> {
+    runtime: Arc<Runtime>,
+    ty:      PhantomData<T>,
+}
+
+impl<T: 'static> SignalSeed<T> {
+    pub fn new(runtime: Arc<Runtime>) -> Self {
+        Self {
+            runtime,
+            ty: PhantomData,
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de> + 'static> DeserializeSeed<'de> for SignalSeed<T> {
+    type Value = Signal<T>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Signal<T>, D::Error> {
+        let value = T::deserialize(deserializer)?;
+
+        Ok(self.runtime.create_signal(value))
+    }
+}
+
+impl<T: Serialize + 'static> Serialize for Signal<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.with(|v| v.serialize(serializer))
+    }
+}
+
+impl<'de, T: Deserialize<'de> + 'static> Deserialize<'de> for Signal<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let runtime = current_runtime_for::<D>("a signal")?;
+
+        SignalSeed::new(runtime).deserialize(deserializer)
+    }
+}
+
+// no-coverage:start
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signal::tests::create_runtime;
+    use serde::de::DeserializeSeed;
+
+    #[derive(Serialize, Deserialize)]
+    struct AppState {
+        name:  Signal<String>,
+        count: Signal<u32>,
+    }
+
+    #[test]
+    fn test_serialize_signal() {
+        let rt = create_runtime();
+        let state = AppState {
+            name:  Arc::clone(&rt).create_signal(String::from("foo")),
+            count: Arc::clone(&rt).create_signal(42),
+        };
+
+        assert_eq!(
+            serde_json::to_string(&state).unwrap(),
+            r#"{"name":"foo","count":42}"#,
+            "signals should be serialized as their current value"
+        );
+    }
+
+    #[test]
+    fn test_deserialize_signal_with_seed() {
+        let rt = create_runtime();
+        let mut deserializer = serde_json::Deserializer::from_str("[1,2,3]");
+        let signal = SignalSeed::<Vec<u32>>::new(Arc::clone(&rt))
+            .deserialize(&mut deserializer)
+            .unwrap();
+
+        assert_eq!(signal.get(), vec![1, 2, 3], "signal should hold the deserialized value");
+        assert_eq!(signal.runtime(), rt, "signal should be created in the seed runtime");
+    }
+
+    #[test]
+    fn test_deserialize_signal_with_current_runtime() {
+        let rt = create_runtime();
+        let state: AppState = rt
+            .with_current(|| serde_json::from_str(r#"{"name":"bar","count":7}"#))
+            .unwrap();
+
+        assert_eq!(state.name.get(), "bar", "signal should hold the deserialized value");
+        assert_eq!(state.count.get(), 7, "signal should hold the deserialized value");
+        assert_eq!(
+            state.count.runtime(),
+            rt,
+            "signal should be created in the current runtime"
+        );
+        assert!(Runtime::current().is_none(), "current runtime should be reset");
+    }
+
+    #[test]
+    fn test_nested_current_runtime() {
+        let rt = create_runtime();
+        let other = create_runtime();
+
+        rt.with_current(|| {
+            other.with_current(|| {
+                assert_eq!(
+                    Runtime::current(),
+                    Some(Arc::clone(&other)),
+                    "innermost runtime should be current"
+                );
+            });
+
+            assert_eq!(
+                Runtime::current(),
+                Some(Arc::clone(&rt)),
+                "outer runtime should be current again"
+            );
+        });
+    }
+
+    #[test]
+    fn test_deserialize_signal_without_runtime() {
+        let err = serde_json::from_str::<Signal<u32>>("1").err().unwrap();
+
+        assert!(
+            err.to_string()
+                .starts_with("no current runtime to deserialize a signal"),
+            "deserializing without a runtime should fail"
+        );
+    }
+}
+// no-coverage:stop
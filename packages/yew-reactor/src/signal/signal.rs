@@ -1,9 +1,21 @@
-use super::{id::SignalId, SignalMap, Runtime};
+use super::{
+    id::SignalId,
+    BufferPolicy,
+    SignalChanged,
+    SignalMap,
+    SignalSender,
+    SignalStream,
+    Runtime,
+    Subscription,
+    WeakSignal,
+};
 use std::{
     fmt::{Display, Debug, Formatter, Result as FmtResult},
     sync::atomic::{AtomicBool, Ordering},
+    panic::AssertUnwindSafe,
     marker::PhantomData,
-    sync::Arc,
+    sync::{Arc, Mutex},
+    mem::replace,
 };
 
 pub struct Signal<T: 'static> {
@@ -35,6 +47,14 @@ impl<T: 'static> Signal<T> {
         Arc::clone(&self.runtime)
     }
 
+    pub(super) fn signal_id(&self) -> SignalId {
+        self.id
+    }
+
+    pub fn version(&self) -> u64 {
+        self.runtime.signal_version(self.id)
+    }
+
     pub fn set(&self, value: T) {
         self.update(|v| *v = value);
     }
@@ -68,6 +88,8 @@ impl<T: 'static> Signal<T> {
     }
 
     pub fn with_another<X: 'static, O, F: FnOnce(&T, &X) -> O>(&self, other: Signal<X>, f: F) -> O {
+        self.runtime.check_same_runtime(&other.runtime, "combine");
+
         let other = other.clone();
 
         self.with(move |v| other.with(|o| f(v, o)))
@@ -92,6 +114,7 @@ impl<T: 'static> Signal<T> {
         }
 
         // notify subscribers
+        runtime.bump_version(id);
         self.runtime().defer(move || {
             runtime.notify_subscribers(id);
         });
@@ -131,13 +154,61 @@ impl<T: 'static> Signal<T> {
 
         // notify subscribers
         if should_notify {
+            runtime.bump_version(id);
             self.runtime().defer(move || {
                 runtime.notify_subscribers(id);
             });
         }
     }
 
+    pub fn update_with_change<C: 'static>(&self, f: impl FnOnce(&mut T) -> C) {
+        let runtime = self.runtime();
+        let id = self.id;
+
+        // set value
+        let change = {
+            let value_ref = {
+                let signal_id = runtime.get_source_id(id);
+
+                runtime.get_value(&signal_id)
+            };
+
+            let mut value = value_ref.write().unwrap();
+            let signal_value = value.downcast_mut::<T>().unwrap();
+
+            AssertUnwindSafe(f(signal_value))
+        };
+
+        // notify subscribers, then the change effects with the change payload
+        let watchers = runtime.change_watchers(id);
+
+        runtime.bump_version(id);
+        self.runtime().defer(move || {
+            runtime.notify_subscribers(id);
+            runtime.notify_change(&watchers, &*change);
+        });
+    }
+
+    /// Calls `f` on each notifying update, without reading the value
+    pub(crate) fn on_notify(&self, f: impl Fn() + 'static) -> Subscription {
+        self.runtime
+            .create_subscription_with(self.id, false, |_: &T| (), move |()| f())
+    }
+
+    pub(crate) fn downgrade(&self) -> WeakSignal<T> {
+        WeakSignal::new(&self.runtime, self.id)
+    }
+
+    pub fn sender(&self) -> SignalSender<T> {
+        self.runtime.update_sender(self.id)
+    }
+
+    pub fn changed(&self) -> SignalChanged {
+        SignalChanged::new::<T>(&self.runtime, self.id)
+    }
+
     pub fn link_to(&self, source: &Signal<T>) {
+        self.runtime.check_same_runtime(&source.runtime, "link");
         self.runtime.link_signal(self.id, source.id);
     }
 
@@ -166,6 +237,30 @@ impl<T: Debug + 'static> Debug for Signal<T> {
     }
 }
 
+impl<T: 'static> Signal<T> {
+    /// Reads the value without subscribing the running effect
+    pub(crate) fn untracked_with<O>(&self, f: impl FnOnce(&T) -> O) -> O {
+        let runtime = self.runtime();
+        let signal_id = runtime.get_source_id(self.id);
+        let value_ref = runtime.get_value(&signal_id);
+        let value = value_ref.read().unwrap();
+
+        f(value.downcast_ref::<T>().unwrap())
+    }
+
+    /// Subscribes the running effect, while keeping the reference of this handle, which is owned by a collection
+    pub(crate) fn track(&self) {
+        self.runtime.add_subscriber(self.id);
+    }
+
+    /// Releases the reference of this handle when read by a running effect, as `with` does, without subscribing it
+    pub(crate) fn release_in_effect(&self) {
+        if self.runtime.is_tracking() && !self.registered.fetch_or(true, Ordering::SeqCst) {
+            self.runtime.dec_signal_ref(self.id);
+        }
+    }
+}
+
 impl<T: Clone + 'static> Signal<T> {
     pub(crate) fn peek(&self) -> T {
         let runtime = self.runtime();
@@ -184,11 +279,48 @@ impl<T: Clone + 'static> Signal<T> {
     pub fn get(&self) -> T {
         self.with(T::clone)
     }
+
+    pub fn to_stream(&self) -> SignalStream<T> {
+        self.to_stream_with(BufferPolicy::Latest)
+    }
+
+    pub fn to_stream_with(&self, policy: BufferPolicy) -> SignalStream<T> {
+        SignalStream::new(&self.runtime, self.id, policy, T::clone)
+    }
+
+    /// The callback receives a clone of the value, so it can update the signal
+    pub fn subscribe(&self, f: impl Fn(&T) + 'static) -> Subscription {
+        self.runtime.create_subscription(self.id, false, f)
+    }
+
+    pub fn subscribe_immediate(&self, f: impl Fn(&T) + 'static) -> Subscription {
+        self.runtime.create_subscription(self.id, true, f)
+    }
+
+    pub fn watch(&self, f: impl Fn(&T, &T) + 'static) -> Subscription {
+        self.create_watch(false, f)
+    }
+
+    /// The first call receives the current value as both the old and the new value
+    pub fn watch_immediate(&self, f: impl Fn(&T, &T) + 'static) -> Subscription {
+        self.create_watch(true, f)
+    }
+
+    fn create_watch(&self, immediate: bool, f: impl Fn(&T, &T) + 'static) -> Subscription {
+        let old = Mutex::new(self.peek());
+
+        self.runtime.create_subscription(self.id, immediate, move |new: &T| {
+            let old_value = replace(&mut *old.lock().unwrap(), new.clone());
+
+            f(&old_value, new);
+        })
+    }
 }
 
 impl<T: 'static> PartialEq for Signal<T> {
     fn eq(&self, other: &Self) -> bool {
-        self.id == other.id
+        // deterministic ids are only unique within a runtime
+        self.runtime == other.runtime && self.id == other.id
     }
 }
 
@@ -202,7 +334,10 @@ impl<T: 'static> Clone for Signal<T> {
 
 impl<T: 'static> Drop for Signal<T> {
     fn drop(&mut self) {
-        self.runtime.clean_signal(self.id);
+        // a handle released by an effect does not hold a reference anymore
+        if !self.registered.load(Ordering::SeqCst) {
+            self.runtime.clean_signal(self.id);
+        }
     }
 }
 
@@ -215,12 +350,6 @@ mod tests {
     use super::*;
     use crate::signal::tests::create_runtime;
 
-    impl<T: 'static> Signal<T> {
-        pub(in super::super) fn signal_id(&self) -> SignalId {
-            self.id
-        }
-    }
-
     #[test]
     fn test_signal_get() {
         let rt = create_runtime();
@@ -417,5 +546,154 @@ mod tests {
 
         assert_eq!(value.load(Ordering::SeqCst), 321, "the stored value should be changed");
     }
+
+    #[test]
+    fn test_signal_version() {
+        let rt = create_runtime();
+        let signal = Arc::clone(&rt).create_signal(42);
+        let other = Arc::clone(&rt).create_signal(0);
+        let link = signal.create_link();
+
+        assert_eq!(signal.version(), 0, "initial version should be 0");
+        assert_eq!(rt.global_version(), 0, "initial global version should be 0");
+
+        signal.set(1);
+        link.update(|v| *v += 1);
+        assert_eq!(signal.version(), 2, "version should be bumped on every update");
+        assert_eq!(link.version(), 2, "version should be shared across links");
+
+        signal.update_if(|_| false);
+        signal.untracked_set(3);
+        assert_eq!(
+            signal.version(),
+            2,
+            "updates without notification should not bump the version"
+        );
+
+        other.set(1);
+        assert_eq!(other.version(), 1, "each source should have its own version");
+        assert_eq!(rt.global_version(), 3, "global version should count all the updates");
+
+        drop(signal);
+        assert_eq!(link.get(), 3, "link should keep the value when its source is dropped");
+        assert_eq!(
+            link.version(),
+            2,
+            "version should follow the value when its source is dropped"
+        );
+    }
+
+    #[test]
+    fn test_signal_subscribe() {
+        let rt = create_runtime();
+        let signal = Arc::clone(&rt).create_signal(1);
+        let other = Arc::clone(&rt).create_signal(10);
+        let values = Arc::new(Mutex::new(vec![]));
+
+        let subscription = {
+            let other = other.clone();
+            let values = Arc::clone(&values);
+
+            signal.subscribe(move |v| values.lock().unwrap().push(*v + other.get()))
+        };
+
+        assert!(
+            values.lock().unwrap().is_empty(),
+            "callback should not be called immediately"
+        );
+
+        signal.set(2);
+        other.set(20);
+        assert_eq!(
+            *values.lock().unwrap(),
+            vec![12],
+            "only the subscribed signal should be tracked"
+        );
+
+        subscription.unsubscribe();
+        signal.set(3);
+        assert_eq!(
+            values.lock().unwrap().len(),
+            1,
+            "callback should not be called after unsubscribing"
+        );
+        assert_eq!(signal.get(), 3, "signal should outlive its subscriptions");
+    }
+
+    #[test]
+    fn test_signal_subscribe_updating_its_signal() {
+        let rt = create_runtime();
+        let signal = Arc::clone(&rt).create_signal(1);
+
+        let _subscription = {
+            let target = signal.clone();
+
+            signal.subscribe(move |v| {
+                if *v % 2 == 1 {
+                    target.set(*v + 1);
+                }
+            })
+        };
+
+        signal.set(3);
+
+        assert_eq!(signal.get(), 4, "callback should be able to update its own signal");
+    }
+
+    #[test]
+    fn test_signal_subscribe_immediate() {
+        let rt = create_runtime();
+        let signal = Arc::clone(&rt).create_signal(1);
+        let values = Arc::new(Mutex::new(vec![]));
+
+        let _subscription = {
+            let values = Arc::clone(&values);
+
+            signal.subscribe_immediate(move |v| values.lock().unwrap().push(*v))
+        };
+
+        signal.set(2);
+        assert_eq!(
+            *values.lock().unwrap(),
+            vec![1, 2],
+            "callback should be called immediately"
+        );
+    }
+
+    #[test]
+    fn test_signal_watch() {
+        let rt = create_runtime();
+        let signal = Arc::clone(&rt).create_signal(1);
+        let values = Arc::new(Mutex::new(vec![]));
+
+        let subscription = {
+            let values = Arc::clone(&values);
+
+            signal.watch(move |old, new| values.lock().unwrap().push((*old, *new)))
+        };
+
+        signal.set(2);
+        signal.set(3);
+        drop(subscription);
+        signal.set(4);
+
+        assert_eq!(
+            *values.lock().unwrap(),
+            vec![(1, 2), (2, 3)],
+            "callback should receive the previous and the new values"
+        );
+
+        let _subscription = {
+            let values = Arc::clone(&values);
+
+            signal.watch_immediate(move |old, new| values.lock().unwrap().push((*old, *new)))
+        };
+
+        assert_eq!(
+            values.lock().unwrap().last(),
+            Some(&(4, 4)),
+            "immediate callback should receive the current value twice"
+        );
+    }
 }
 // no-coverage:stop
@@ -0,0 +1,380 @@
+use super::{Runtime, Signal, Subscription};
+use std::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    panic::AssertUnwindSafe,
+    sync::Arc,
+};
+
+/// Structural change of a signal vector, the values themselves are updated through the item signals
+#[derive(Clone, Debug, PartialEq)]
+pub enum VecDiff<T: 'static> {
+    Push(Signal<T>),
+    Insert { index: usize, item: Signal<T> },
+    Remove { index: usize },
+    Move { from: usize, to: usize },
+    Swap { a: usize, b: usize },
+    Replace { index: usize },
+    Clear,
+}
+
+/// Vector holding a signal per item, each operation emits a diff to the watchers of the vector
+pub struct SignalVec<T: 'static> {
+    items: Signal<Vec<Signal<T>>>,
+}
+
+impl<T: 'static> SignalVec<T> {
+    pub(super) fn new(runtime: Arc<Runtime>, values: Vec<T>) -> Self {
+        let items = values
+            .into_iter()
+            .map(|value| Arc::clone(&runtime).create_signal(value))
+            .collect();
+
+        Self {
+            items: runtime.create_signal(items),
+        }
+    }
+
+    pub fn id(&self) -> String {
+        self.items.id()
+    }
+
+    pub fn runtime(&self) -> Arc<Runtime> {
+        self.items.runtime()
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.with(Vec::len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.with(Vec::is_empty)
+    }
+
+    pub fn item(&self, index: usize) -> Option<Signal<T>> {
+        self.items.with(|items| items.get(index).cloned())
+    }
+
+    pub fn items(&self) -> Vec<Signal<T>> {
+        self.items.get()
+    }
+
+    pub fn push(&self, value: T) {
+        let item = self.runtime().create_signal(value);
+
+        self.items.update_with_change(|items| {
+            items.push(item.clone());
+
+            VecDiff::Push(item)
+        });
+    }
+
+    /// Returns the value back when the index is out of bounds, the vector is then left untouched
+    pub fn insert(&self, index: usize, value: T) -> Result<(), T> {
+        // the bounds are checked before updating, as a panic under the lock would poison it
+        if index > self.items.untracked_with(Vec::len) {
+            return Err(value);
+        }
+
+        let item = self.runtime().create_signal(value);
+
+        self.items.update_with_change(|items| {
+            items.insert(index, item.clone());
+
+            VecDiff::Insert {
+                index,
+                item,
+            }
+        });
+
+        Ok(())
+    }
+
+    /// Removes the item, and returns its signal, or `None` when the index is out of bounds
+    pub fn remove(&self, index: usize) -> Option<Signal<T>> {
+        if index >= self.items.untracked_with(Vec::len) {
+            return None;
+        }
+
+        let mut removed = None;
+
+        self.items.update_with_change(|items| {
+            removed = Some(items.remove(index));
+
+            VecDiff::<T>::Remove {
+                index,
+            }
+        });
+
+        removed
+    }
+
+    /// Returns `false` when an index is out of bounds, the vector is then left untouched
+    pub fn move_item(&self, from: usize, to: usize) -> bool {
+        let len = self.items.untracked_with(Vec::len);
+
+        if from >= len || to >= len {
+            return false;
+        }
+
+        self.items.update_with_change(|items| {
+            let item = items.remove(from);
+            items.insert(to, item);
+
+            VecDiff::<T>::Move {
+                from,
+                to,
+            }
+        });
+
+        true
+    }
+
+    /// Returns `false` when an index is out of bounds, the vector is then left untouched
+    pub fn swap(&self, a: usize, b: usize) -> bool {
+        let len = self.items.untracked_with(Vec::len);
+
+        if a >= len || b >= len {
+            return false;
+        }
+
+        self.items.update_with_change(|items| {
+            items.swap(a, b);
+
+            VecDiff::<T>::Swap {
+                a,
+                b,
+            }
+        });
+
+        true
+    }
+
+    /// Sets the value of an item, only the item signal and the diff watchers are notified
+    pub fn replace(&self, index: usize, value: T) {
+        let item = self
+            .items
+            .with(|items| items.get(index).cloned())
+            .unwrap_or_else(|| panic!("Out of bounds: no item at index {index} in the signal vector"));
+
+        item.set(value);
+
+        let runtime = self.runtime();
+        let watchers = runtime.change_watchers(self.items.signal_id());
+        let change = AssertUnwindSafe(VecDiff::<T>::Replace {
+            index,
+        });
+
+        self.runtime().defer(move || {
+            runtime.notify_change(&watchers, &*change);
+        });
+    }
+
+    pub fn clear(&self) {
+        self.items.update_with_change(|items| {
+            items.clear();
+
+            VecDiff::<T>::Clear
+        });
+    }
+
+    pub fn on_diff(&self, f: impl Fn(&VecDiff<T>) + 'static) -> Subscription {
+        self.runtime().create_change_effect(&self.items, f)
+    }
+
+    pub fn link_to(&self, source: &SignalVec<T>) {
+        self.items.link_to(&source.items);
+    }
+}
+
+impl<T: Clone + 'static> SignalVec<T> {
+    pub fn get(&self, index: usize) -> Option<T> {
+        self.item(index).map(|item| item.get())
+    }
+
+    pub fn to_vec(&self) -> Vec<T> {
+        self.items.with(|items| items.iter().map(Signal::get).collect())
+    }
+}
+
+impl<T: 'static> Clone for SignalVec<T> {
+    fn clone(&self) -> Self {
+        Self {
+            items: self.items.clone(),
+        }
+    }
+}
+
+impl<T: 'static> PartialEq for SignalVec<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.items == other.items
+    }
+}
+
+impl<T: 'static> Eq for SignalVec<T> {}
+
+impl<T: 'static> Debug for SignalVec<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "SignalVec[{}]", self.items.id())
+    }
+}
+
+// no-coverage:start
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signal::tests::{count_notifications, count_runs, create_runtime, QueuedRunner};
+    use std::sync::Mutex;
+
+    fn collect_diffs<T: Clone + 'static>(vec: &SignalVec<T>) -> (Arc<Mutex<Vec<VecDiff<T>>>>, Subscription) {
+        let diffs = Arc::new(Mutex::new(vec![]));
+        let subscription = {
+            let diffs = Arc::clone(&diffs);
+
+            vec.on_diff(move |diff| diffs.lock().unwrap().push(diff.clone()))
+        };
+
+        (diffs, subscription)
+    }
+
+    #[test]
+    fn test_signal_vec_operations() {
+        let rt = create_runtime();
+        let vec = Arc::clone(&rt).create_signal_vec(vec![1, 2]);
+        let (diffs, _subscription) = collect_diffs(&vec);
+
+        vec.push(3);
+        assert_eq!(vec.insert(0, 0), Ok(()), "value should be inserted");
+        assert_eq!(vec.to_vec(), vec![0, 1, 2, 3], "values should be pushed and inserted");
+
+        assert!(vec.move_item(0, 3), "item should be moved");
+        assert_eq!(vec.to_vec(), vec![1, 2, 3, 0], "item should be moved");
+
+        assert!(vec.swap(0, 1), "items should be swapped");
+        assert_eq!(vec.to_vec(), vec![2, 1, 3, 0], "items should be swapped");
+
+        assert_eq!(
+            vec.remove(3).map(|item| item.get()),
+            Some(0),
+            "removed item signal should be returned"
+        );
+
+        vec.replace(1, 10);
+        assert_eq!(vec.get(1), Some(10), "item should be replaced");
+
+        let pushed = vec.item(2).unwrap();
+        vec.clear();
+        assert!(vec.is_empty(), "vector should be cleared");
+
+        let diffs = diffs.lock().unwrap();
+
+        assert_eq!(diffs.len(), 7, "each operation should emit a diff");
+        assert_eq!(diffs[0], VecDiff::Push(pushed), "push should carry the new item signal");
+        assert!(
+            matches!(
+                diffs[1],
+                VecDiff::Insert {
+                    index: 0,
+                    ..
+                }
+            ),
+            "insert should carry its index"
+        );
+        assert_eq!(
+            diffs[2..],
+            [
+                VecDiff::Move {
+                    from: 0, to: 3
+                },
+                VecDiff::Swap {
+                    a: 0, b: 1
+                },
+                VecDiff::Remove {
+                    index: 3
+                },
+                VecDiff::Replace {
+                    index: 1
+                },
+                VecDiff::Clear,
+            ],
+            "diffs should be emitted in order"
+        );
+    }
+
+    #[test]
+    fn test_signal_vec_out_of_bounds() {
+        let rt = create_runtime();
+        let vec = Arc::clone(&rt).create_signal_vec(vec![1, 2]);
+        let (diffs, _subscription) = collect_diffs(&vec);
+
+        assert_eq!(vec.insert(3, 3), Err(3), "value should be returned back");
+        assert!(vec.remove(2).is_none(), "nothing should be removed");
+        assert!(!vec.move_item(0, 2), "item should not be moved");
+        assert!(!vec.swap(2, 0), "items should not be swapped");
+
+        assert_eq!(vec.to_vec(), vec![1, 2], "vector should be left untouched");
+        assert!(diffs.lock().unwrap().is_empty(), "no diff should be emitted");
+
+        vec.push(3);
+        assert_eq!(vec.to_vec(), vec![1, 2, 3], "vector should still be usable");
+    }
+
+    #[test]
+    fn test_signal_vec_pending_diffs() {
+        let runner = QueuedRunner::default();
+        let rt = Runtime::new().with_defer_runner(runner.clone());
+        let vec = Arc::clone(&rt).create_signal_vec(vec![1]);
+        let (before, _before_subscription) = collect_diffs(&vec);
+
+        vec.push(2);
+        vec.replace(0, 10);
+
+        // a watcher created before the delivery reads the vector with the pushed item
+        let (after, _after_subscription) = collect_diffs(&vec);
+
+        vec.remove(0);
+        runner.run_all();
+
+        assert_eq!(
+            before.lock().unwrap().len(),
+            3,
+            "earlier watcher should get all the diffs"
+        );
+        assert_eq!(
+            *after.lock().unwrap(),
+            vec![VecDiff::Remove {
+                index: 0
+            }],
+            "later watcher should only get the diffs emitted after its creation"
+        );
+    }
+
+    #[test]
+    fn test_signal_vec_item_updates() {
+        let rt = create_runtime();
+        let vec = Arc::clone(&rt).create_signal_vec(vec!["a".to_string(), "b".to_string()]);
+        let structure_runs = count_runs(&rt, {
+            let vec = vec.clone();
+            move || {
+                vec.len();
+            }
+        });
+        let item_runs = count_notifications(&rt, &vec.item(0).unwrap());
+
+        vec.replace(0, "c".to_string());
+        vec.item(1).unwrap().set("d".to_string());
+
+        assert_eq!(vec.to_vec(), vec!["c", "d"], "items should be updated");
+        assert_eq!(item_runs.get(), 2, "replacing an item should notify the item signal");
+        assert_eq!(
+            structure_runs.get(),
+            1,
+            "updating items should not notify the structure"
+        );
+
+        vec.push("e".to_string());
+
+        assert_eq!(structure_runs.get(), 2, "pushing should notify the structure");
+        assert_eq!(item_runs.get(), 2, "pushing should not notify the other items");
+    }
+}
+// no-coverage:stop
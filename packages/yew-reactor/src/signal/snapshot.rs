@@ -0,0 +1,462 @@
+use super::{id::SignalId, Runtime};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{Error as JsonError, Value as JsonValue};
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, RwLock},
+    any::Any,
+};
+
+type SaveFn = Box<dyn Fn(&dyn Any) -> Result<JsonValue, JsonError>>;
+type ApplyFn = Box<dyn FnOnce(&mut dyn Any)>;
+type LoadFn = Box<dyn Fn(JsonValue) -> Result<ApplyFn, JsonError>>;
+
+/// Converts the value of a registered signal from and to a JSON value
+pub trait StateSerializer<T> {
+    fn serialize(&self, value: &T) -> Result<JsonValue, JsonError>;
+
+    fn deserialize(&self, value: JsonValue) -> Result<T, JsonError>;
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct JsonStateSerializer;
+
+impl<T: Serialize + DeserializeOwned> StateSerializer<T> for JsonStateSerializer {
+    fn serialize(&self, value: &T) -> Result<JsonValue, JsonError> {
+        serde_json::to_value(value)
+    }
+
+    fn deserialize(&self, value: JsonValue) -> Result<T, JsonError> {
+        serde_json::from_value(value)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct StateSnapshot {
+    values: BTreeMap<String, JsonValue>,
+}
+
+impl StateSnapshot {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, JsonError> {
+        serde_json::from_str(json)
+    }
+
+    pub fn to_json(&self) -> Result<String, JsonError> {
+        serde_json::to_string(self)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.values.contains_key(key)
+    }
+
+    pub fn get(&self, key: &str) -> Option<&JsonValue> {
+        self.values.get(key)
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.values.keys().map(String::as_str)
+    }
+
+    pub fn insert(&mut self, key: &str, value: JsonValue) {
+        self.values.insert(key.to_string(), value);
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<JsonValue> {
+        self.values.remove(key)
+    }
+}
+
+struct StateEntry {
+    signal_id: SignalId,
+    save:      SaveFn,
+    load:      LoadFn,
+    retained:  bool,
+}
+
+#[derive(Default)]
+pub(super) struct StateRegistry {
+    entries: RwLock<BTreeMap<String, StateEntry>>,
+    pending: RwLock<StateSnapshot>,
+}
+
+impl StateRegistry {
+    pub(super) fn register<T: 'static>(
+        &self,
+        runtime: &Runtime,
+        key: &str,
+        signal_id: SignalId,
+        serializer: impl StateSerializer<T> + 'static,
+        retained: bool,
+    ) -> bool {
+        let serializer = Arc::new(serializer);
+        let save = {
+            let serializer = Arc::clone(&serializer);
+
+            Box::new(move |value: &dyn Any| serializer.serialize(value.downcast_ref::<T>().unwrap()))
+        };
+
+        let load = Box::new(move |json: JsonValue| -> Result<ApplyFn, JsonError> {
+            let new_value = serializer.deserialize(json)?;
+
+            Ok(Box::new(move |value: &mut dyn Any| {
+                *value.downcast_mut::<T>().unwrap() = new_value;
+            }))
+        });
+
+        let entry = StateEntry {
+            signal_id,
+            save,
+            load,
+            retained,
+        };
+
+        // a retained signal is kept alive by the registry until it is unregistered
+        if retained {
+            runtime.inc_signal_ref(signal_id);
+        }
+
+        // a value waiting for hydration is applied as soon as its signal is registered
+        let hydrated = match self.pending.write().unwrap().remove(key) {
+            Some(json) => Self::apply(runtime, &entry, json),
+            None => false,
+        };
+
+        let replaced = self.entries.write().unwrap().insert(key.to_string(), entry);
+
+        if let Some(replaced) = replaced {
+            Self::release(runtime, replaced);
+        }
+
+        hydrated
+    }
+
+    pub(super) fn unregister(&self, runtime: &Runtime, key: &str) -> bool {
+        let removed = self.entries.write().unwrap().remove(key);
+
+        match removed {
+            Some(entry) => {
+                Self::release(runtime, entry);
+
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn release(runtime: &Runtime, entry: StateEntry) {
+        if entry.retained {
+            runtime.clean_signal(entry.signal_id);
+        }
+    }
+
+    pub(super) fn snapshot(&self, runtime: &Runtime) -> Result<StateSnapshot, JsonError> {
+        let (snapshot, errors) = self.partial_snapshot(runtime);
+
+        match errors.into_iter().next() {
+            Some((_, err)) => Err(err),
+            None => Ok(snapshot),
+        }
+    }
+
+    pub(super) fn partial_snapshot(&self, runtime: &Runtime) -> (StateSnapshot, Vec<(String, JsonError)>) {
+        let mut snapshot = StateSnapshot::new();
+        let mut errors = vec![];
+
+        for (key, entry) in self.entries.read().unwrap().iter() {
+            // signals which have been dropped since their registration are ignored
+            if let Some(value_ref) = runtime.find_value(entry.signal_id) {
+                let value = value_ref.read().unwrap();
+
+                match (entry.save)(&*value) {
+                    Ok(json) => snapshot.insert(key, json),
+                    Err(err) => errors.push((key.clone(), err)),
+                }
+            }
+        }
+
+        (snapshot, errors)
+    }
+
+    pub(super) fn restore(&self, runtime: &Runtime, snapshot: &StateSnapshot) -> Result<Vec<SignalId>, JsonError> {
+        // all the values are decoded before any signal is changed, so a snapshot is either fully restored or not at all
+        let mut loaded = vec![];
+
+        for (key, entry) in self.entries.read().unwrap().iter() {
+            if let Some(json) = snapshot.get(key) {
+                loaded.push((entry.signal_id, (entry.load)(json.clone())?));
+            }
+        }
+
+        let mut restored = vec![];
+
+        for (signal_id, apply) in loaded {
+            if let Some(value_ref) = runtime.find_value(signal_id) {
+                apply(&mut *value_ref.write().unwrap());
+                restored.push(signal_id);
+            }
+        }
+
+        Ok(restored)
+    }
+
+    pub(super) fn hydrate(&self, runtime: &Runtime, snapshot: StateSnapshot) -> Vec<SignalId> {
+        let entries = self.entries.read().unwrap();
+        let mut pending = self.pending.write().unwrap();
+        let mut hydrated = vec![];
+
+        // unlike a restoration, an invalid value is skipped and its signal keeps its initial value
+        for (key, json) in snapshot.values {
+            match entries.get(&key) {
+                Some(entry) => {
+                    if Self::apply(runtime, entry, json) {
+                        hydrated.push(entry.signal_id);
+                    }
+                }
+                None => {
+                    pending.values.insert(key, json);
+                }
+            }
+        }
+
+        hydrated
+    }
+
+    fn apply(runtime: &Runtime, entry: &StateEntry, json: JsonValue) -> bool {
+        match ((entry.load)(json), runtime.find_value(entry.signal_id)) {
+            (Ok(apply), Some(value_ref)) => {
+                apply(&mut *value_ref.write().unwrap());
+
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+// no-coverage:start
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signal::tests::create_runtime;
+    use serde_json::json;
+    use std::{
+        collections::HashMap,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    struct PrefixSerializer;
+
+    impl StateSerializer<String> for PrefixSerializer {
+        fn serialize(&self, value: &String) -> Result<JsonValue, JsonError> {
+            Ok(JsonValue::String(format!("prefix:{value}")))
+        }
+
+        fn deserialize(&self, value: JsonValue) -> Result<String, JsonError> {
+            let value = String::deserialize(value)?;
+
+            Ok(value.trim_start_matches("prefix:").to_string())
+        }
+    }
+
+    #[test]
+    fn test_snapshot_json() {
+        let mut snapshot = StateSnapshot::new();
+
+        assert!(snapshot.is_empty(), "a new snapshot should be empty");
+
+        snapshot.insert("b", json!(2));
+        snapshot.insert("a", json!("one"));
+
+        let json = snapshot.to_json().unwrap();
+
+        assert_eq!(
+            json, r#"{"a":"one","b":2}"#,
+            "snapshot should be serialized as a JSON object"
+        );
+        assert_eq!(
+            StateSnapshot::from_json(&json).unwrap(),
+            snapshot,
+            "snapshot should be deserialized from JSON"
+        );
+
+        assert_eq!(snapshot.len(), 2, "snapshot should contain 2 values");
+        assert_eq!(snapshot.keys().collect::<Vec<_>>(), vec!["a", "b"]);
+        assert_eq!(snapshot.remove("a"), Some(json!("one")));
+        assert!(!snapshot.contains("a"), "removed key should not be contained");
+    }
+
+    #[test]
+    fn test_take_snapshot() {
+        let rt = create_runtime();
+        let count = Arc::clone(&rt).create_signal(42);
+        let name = Arc::clone(&rt).create_signal(String::from("foo"));
+        let link = count.create_link();
+
+        rt.register_state("count", &link);
+        rt.register_state_with("name", &name, PrefixSerializer);
+
+        let snapshot = rt.take_snapshot().unwrap();
+
+        assert_eq!(snapshot.get("count"), Some(&json!(42)), "linked value should be saved");
+        assert_eq!(
+            snapshot.get("name"),
+            Some(&json!("prefix:foo")),
+            "value should be saved with the provided serializer"
+        );
+
+        rt.unregister_state("name");
+        drop(link);
+
+        assert!(
+            rt.take_snapshot().unwrap().is_empty(),
+            "unregistered and dropped signals should not be saved"
+        );
+    }
+
+    #[test]
+    fn test_take_partial_snapshot() {
+        let rt = create_runtime();
+        let count = Arc::clone(&rt).create_signal(42);
+        let pairs = Arc::clone(&rt).create_signal(HashMap::from([((1, 2), 3)]));
+
+        rt.register_state("count", &count);
+        rt.register_state("pairs", &pairs);
+
+        assert!(
+            rt.take_snapshot().is_err(),
+            "snapshot should fail on an unserializable value"
+        );
+
+        let (snapshot, errors) = rt.take_partial_snapshot();
+
+        assert_eq!(
+            snapshot.keys().collect::<Vec<_>>(),
+            vec!["count"],
+            "only the serializable values should be saved"
+        );
+        assert_eq!(
+            errors.iter().map(|(key, _)| key.as_str()).collect::<Vec<_>>(),
+            vec!["pairs"],
+            "the unserializable values should be reported"
+        );
+    }
+
+    #[test]
+    fn test_restore_snapshot() {
+        let rt = create_runtime();
+        let count = Arc::clone(&rt).create_signal(0);
+        let name = Arc::clone(&rt).create_signal(String::new());
+        let other = Arc::clone(&rt).create_signal(7);
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        rt.register_state("count", &count);
+        rt.register_state_with("name", &name, PrefixSerializer);
+        rt.register_state("other", &other);
+
+        {
+            let count = count.clone();
+            let calls = Arc::clone(&calls);
+
+            rt.create_effect(move || {
+                count.with(|_| calls.fetch_add(1, Ordering::SeqCst));
+            });
+        }
+
+        let snapshot = StateSnapshot::from_json(r#"{"count":42,"name":"prefix:bar","unknown":1}"#).unwrap();
+
+        rt.restore_snapshot(&snapshot).unwrap();
+
+        assert_eq!(count.get(), 42, "value should be restored");
+        assert_eq!(
+            name.get(),
+            "bar",
+            "value should be restored with the provided serializer"
+        );
+        assert_eq!(other.get(), 7, "value missing from the snapshot should not be changed");
+        assert_eq!(calls.load(Ordering::SeqCst), 2, "restoring should notify subscribers");
+    }
+
+    #[test]
+    fn test_restore_invalid_snapshot() {
+        let rt = create_runtime();
+        let count = Arc::clone(&rt).create_signal(0);
+
+        rt.register_state("count", &count);
+
+        let snapshot = StateSnapshot::from_json(r#"{"count":"not a number"}"#).unwrap();
+
+        assert!(
+            rt.restore_snapshot(&snapshot).is_err(),
+            "invalid value should be rejected"
+        );
+        assert_eq!(count.get(), 0, "value should not be changed");
+    }
+
+    #[test]
+    fn test_hydrate_state() {
+        let rt = create_runtime();
+        let count = Arc::clone(&rt).create_signal(0);
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        rt.register_state("count", &count);
+
+        {
+            let count = count.clone();
+            let calls = Arc::clone(&calls);
+
+            rt.create_effect(move || {
+                count.with(|_| calls.fetch_add(1, Ordering::SeqCst));
+            });
+        }
+
+        rt.hydrate_state(StateSnapshot::from_json(r#"{"count":42,"name":"prefix:bar","other":"invalid"}"#).unwrap());
+
+        assert_eq!(count.get(), 42, "registered value should be hydrated immediately");
+        assert_eq!(calls.load(Ordering::SeqCst), 2, "hydration should notify subscribers");
+
+        let name = Arc::clone(&rt).create_signal(String::new());
+        let other = Arc::clone(&rt).create_signal(7);
+
+        rt.register_state_with("name", &name, PrefixSerializer);
+        rt.register_state("other", &other);
+
+        assert_eq!(name.get(), "bar", "value should be hydrated on registration");
+        assert_eq!(other.get(), 7, "invalid value should be ignored");
+    }
+
+    #[test]
+    fn test_retained_states() {
+        let rt = create_runtime().with_retained_states();
+        let count = Arc::clone(&rt).create_signal(42);
+        let signal_id = count.signal_id();
+
+        rt.register_state("count", &count);
+        drop(count);
+
+        assert_eq!(
+            rt.take_snapshot().unwrap().get("count"),
+            Some(&json!(42)),
+            "retained value should be saved after its signal is dropped"
+        );
+
+        assert!(rt.unregister_state("count"), "state should be unregistered");
+        assert!(
+            rt.find_value(signal_id).is_none(),
+            "retained value should be dropped when unregistered"
+        );
+    }
+}
+// no-coverage:stop
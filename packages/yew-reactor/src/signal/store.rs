@@ -0,0 +1,114 @@
+use std::{
+    collections::HashMap,
+    any::{Any, TypeId},
+    fmt::{Debug, Formatter, Result as FmtResult},
+    mem::take,
+    sync::RwLock,
+    rc::Rc,
+};
+
+type StoreValues = HashMap<TypeId, Box<dyn Any>>;
+
+#[derive(Default)]
+struct ScopeData {
+    parent: Option<StoreScope>,
+    values: RwLock<StoreValues>,
+}
+
+/// Type-keyed registry of shared values, owned by a level of the component tree,
+/// a child scope shadows the values of its parents, and its values are dropped with its last handle
+#[derive(Clone, Default)]
+pub struct StoreScope(Rc<ScopeData>);
+
+impl StoreScope {
+    /// Scope falling back to the values of this scope
+    pub fn child(&self) -> Self {
+        Self(Rc::new(ScopeData {
+            parent: Some(self.clone()),
+            values: RwLock::default(),
+        }))
+    }
+
+    /// Stores the value in this scope, replacing the value of the same type
+    pub fn provide<T: 'static>(&self, value: T) {
+        let replaced = self
+            .0
+            .values
+            .write()
+            .unwrap()
+            .insert(TypeId::of::<T>(), Box::new(value));
+
+        // the replaced value is dropped outside of the lock, as it can hold signals
+        drop(replaced);
+    }
+
+    /// Value of the type stored in this scope or in the nearest parent
+    pub fn get<T: Clone + 'static>(&self) -> Option<T> {
+        let value = self
+            .0
+            .values
+            .read()
+            .unwrap()
+            .get(&TypeId::of::<T>())
+            .map(|value| value.downcast_ref::<T>().unwrap().clone());
+
+        value.or_else(|| self.0.parent.as_ref()?.get::<T>())
+    }
+
+    pub(super) fn clear(&self) -> StoreValues {
+        take(&mut *self.0.values.write().unwrap())
+    }
+}
+
+impl PartialEq for StoreScope {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for StoreScope {}
+
+impl Debug for StoreScope {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "StoreScope[{} values]", self.0.values.read().unwrap().len())
+    }
+}
+
+// no-coverage:start
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_child_scope() {
+        let root = StoreScope::default();
+        root.provide("root");
+        root.provide(1_u8);
+
+        let child = root.child();
+        child.provide("child");
+
+        assert_eq!(
+            (child.get::<&str>(), child.get::<u8>()),
+            (Some("child"), Some(1)),
+            "child scope should shadow its parents and fall back to them"
+        );
+        assert_eq!(
+            root.get::<&str>(),
+            Some("root"),
+            "child values should not leak to the parent"
+        );
+
+        let value = Arc::new(());
+        child.provide(Arc::clone(&value));
+        drop(child);
+
+        assert_eq!(
+            Arc::strong_count(&value),
+            1,
+            "child values should be dropped with the scope"
+        );
+    }
+}
+// no-coverage:stop
@@ -0,0 +1,308 @@
+use super::{id::SignalId, Runtime, Subscription};
+use futures::Stream;
+use std::{
+    task::{Context, Poll, Waker},
+    collections::VecDeque,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    sync::{Arc, Mutex},
+    future::Future,
+    pin::Pin,
+};
+
+type SharedChannel<V> = Arc<Mutex<Channel<V>>>;
+
+/// Buffering policy of the values of a signal stream which have not been consumed yet
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BufferPolicy {
+    /// Only the latest value is kept
+    #[default]
+    Latest,
+
+    /// Up to `n` values are kept, the oldest ones are dropped first
+    Bounded(usize),
+}
+
+struct Channel<V> {
+    queue:  VecDeque<V>,
+    waker:  Option<Waker>,
+    closed: bool,
+    policy: BufferPolicy,
+}
+
+impl<V> Channel<V> {
+    fn new_shared(policy: BufferPolicy) -> SharedChannel<V> {
+        Arc::new(Mutex::new(Self {
+            queue: VecDeque::new(),
+            waker: None,
+            closed: false,
+            policy,
+        }))
+    }
+
+    fn poll_value(&mut self, cx: &mut Context<'_>) -> Poll<Option<V>> {
+        if let Some(value) = self.queue.pop_front() {
+            return Poll::Ready(Some(value));
+        }
+
+        if self.closed {
+            return Poll::Ready(None);
+        }
+
+        self.waker = Some(cx.waker().clone());
+
+        Poll::Pending
+    }
+}
+
+// the sender lives in the subscription effect, so the channel is closed when the effect is removed
+struct ChannelSender<V>(SharedChannel<V>);
+
+impl<V> ChannelSender<V> {
+    fn send(&self, value: V) {
+        let mut channel = self.0.lock().unwrap();
+        let capacity = match channel.policy {
+            BufferPolicy::Latest => 1,
+            BufferPolicy::Bounded(n) => n.max(1),
+        };
+
+        while channel.queue.len() >= capacity {
+            channel.queue.pop_front();
+        }
+
+        channel.queue.push_back(value);
+
+        if let Some(waker) = channel.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl<V> Drop for ChannelSender<V> {
+    fn drop(&mut self) {
+        let mut channel = self.0.lock().unwrap();
+
+        channel.closed = true;
+
+        if let Some(waker) = channel.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// Error of a `SignalChanged` future whose signal has been dropped before any notifying update
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SignalDropped;
+
+impl Display for SignalDropped {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "signal dropped before changing")
+    }
+}
+
+impl Error for SignalDropped {}
+
+/// Future resolved on the next notifying update of a signal, or with `SignalDropped` when the signal is dropped
+pub struct SignalChanged {
+    channel:       SharedChannel<()>,
+    _subscription: Subscription,
+}
+
+impl SignalChanged {
+    pub(super) fn new<S: 'static>(runtime: &Arc<Runtime>, id: SignalId) -> Self {
+        let channel = Channel::new_shared(BufferPolicy::Latest);
+        let sender = ChannelSender(Arc::clone(&channel));
+
+        Self {
+            channel,
+            _subscription: runtime.create_subscription_with(id, false, |_: &S| (), move |()| sender.send(())),
+        }
+    }
+}
+
+impl Future for SignalChanged {
+    type Output = Result<(), SignalDropped>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.channel
+            .lock()
+            .unwrap()
+            .poll_value(cx)
+            .map(|value| value.ok_or(SignalDropped))
+    }
+}
+
+/// Stream of the values of a signal, which ends when the signal is dropped
+pub struct SignalStream<V> {
+    channel:       SharedChannel<V>,
+    _subscription: Subscription,
+}
+
+impl<V: 'static> SignalStream<V> {
+    pub(super) fn new<S: 'static>(
+        runtime: &Arc<Runtime>,
+        id: SignalId,
+        policy: BufferPolicy,
+        f: impl Fn(&S) -> V + 'static,
+    ) -> Self {
+        let channel = Channel::new_shared(policy);
+        let sender = ChannelSender(Arc::clone(&channel));
+
+        Self {
+            channel,
+            _subscription: runtime.create_subscription_with(id, false, f, move |value| sender.send(value)),
+        }
+    }
+}
+
+impl<V> Stream for SignalStream<V> {
+    type Item = V;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<V>> {
+        self.channel.lock().unwrap().poll_value(cx)
+    }
+}
+
+// no-coverage:start
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signal::tests::create_runtime;
+    use futures::{executor::block_on, StreamExt};
+
+    #[test]
+    fn test_signal_changed() {
+        let rt = create_runtime();
+        let signal = Arc::clone(&rt).create_signal(1);
+        let changed = signal.changed();
+
+        signal.set(2);
+
+        assert_eq!(block_on(changed), Ok(()), "future should be resolved by the update");
+        assert_eq!(signal.get(), 2, "future should be resolved after the update");
+    }
+
+    #[test]
+    fn test_signal_changed_when_dropped() {
+        let rt = create_runtime();
+        let signal = Arc::clone(&rt).create_signal(1);
+        let changed = signal.changed();
+
+        drop(signal);
+
+        assert_eq!(
+            block_on(changed),
+            Err(SignalDropped),
+            "future should tell that the signal has been dropped"
+        );
+    }
+
+    #[test]
+    fn test_signal_stream_latest() {
+        let rt = create_runtime();
+        let signal = Arc::clone(&rt).create_signal(1);
+        let mut stream = signal.to_stream();
+
+        signal.set(2);
+        signal.set(3);
+        assert_eq!(block_on(stream.next()), Some(3), "only the latest value should be kept");
+
+        drop(signal);
+        assert_eq!(
+            block_on(stream.next()),
+            None,
+            "stream should end when the signal is dropped"
+        );
+    }
+
+    #[test]
+    fn test_signal_stream_bounded() {
+        let rt = create_runtime();
+        let signal = Arc::clone(&rt).create_signal(1);
+        let stream = signal.to_stream_with(BufferPolicy::Bounded(2));
+
+        for v in 2..=4 {
+            signal.set(v);
+        }
+
+        drop(signal);
+        assert_eq!(
+            block_on(stream.collect::<Vec<_>>()),
+            vec![3, 4],
+            "the oldest values should be dropped"
+        );
+    }
+
+    #[test]
+    fn test_signal_map_stream() {
+        let rt = create_runtime();
+        let signal = Arc::clone(&rt).create_signal(1);
+        let map = crate::signal::SignalMap::new(Arc::clone(&rt), signal.signal_id(), |v: &i32| v.to_string());
+        let mut stream = map.to_stream();
+        let changed = map.changed();
+
+        signal.set(2);
+        assert_eq!(block_on(changed), Ok(()), "mapped signal should be changed");
+        assert_eq!(
+            block_on(stream.next()),
+            Some(String::from("2")),
+            "stream should yield mapped values"
+        );
+    }
+
+    // the tasks run on the thread of the runtime, as the streams are not `Send`
+    #[cfg(feature = "task-spawner")]
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_signal_changed_with_task_spawner() {
+        use crate::{
+            spawner::{generators::TaskSpawner, LocalFuture},
+            defer::runners::RunnerForTests,
+        };
+        use std::{
+            sync::atomic::{AtomicUsize, Ordering},
+            panic::AssertUnwindSafe,
+            time::Duration,
+        };
+        use tokio::{task::yield_now, time::timeout};
+
+        let rt = Runtime::new()
+            .with_spawn_generator(TaskSpawner::new())
+            .with_defer_runner(RunnerForTests::new());
+
+        let signal = Arc::clone(&rt).create_signal(1);
+        let received = Arc::new(AtomicUsize::new(0));
+
+        {
+            let mut stream = signal.to_stream();
+            let changed = signal.changed();
+            let received = Arc::clone(&received);
+
+            rt.spawn(LocalFuture::new(AssertUnwindSafe(async move {
+                changed.await.unwrap();
+
+                while let Some(v) = stream.next().await {
+                    received.store(v, Ordering::SeqCst);
+                }
+            })));
+        }
+
+        // a value which is never received fails the test instead of hanging it
+        let receive = |value| {
+            let received = Arc::clone(&received);
+
+            timeout(Duration::from_secs(5), async move {
+                while received.load(Ordering::SeqCst) != value {
+                    yield_now().await;
+                }
+            })
+        };
+
+        yield_now().await;
+        signal.set(2);
+        receive(2).await.expect("first value should be received");
+
+        signal.set(3);
+        receive(3).await.expect("second value should be received");
+    }
+}
+// no-coverage:stop
@@ -0,0 +1,44 @@
+use super::{
+    id::{EffectId, SignalId},
+    Runtime,
+};
+use std::sync::Weak;
+
+/// Handle of a subscription to a signal, which unsubscribes when dropped
+#[must_use = "the subscription is cancelled when dropped"]
+pub struct Subscription {
+    runtime:   Weak<Runtime>,
+    effect_id: EffectId,
+    change_of: Option<SignalId>,
+}
+
+impl Subscription {
+    pub(super) fn new(runtime: Weak<Runtime>, effect_id: EffectId) -> Self {
+        Self {
+            runtime,
+            effect_id,
+            change_of: None,
+        }
+    }
+
+    pub(super) fn for_change(runtime: Weak<Runtime>, signal_id: SignalId, effect_id: EffectId) -> Self {
+        Self {
+            runtime,
+            effect_id,
+            change_of: Some(signal_id),
+        }
+    }
+
+    pub fn unsubscribe(self) {}
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        if let Some(runtime) = self.runtime.upgrade() {
+            match self.change_of {
+                Some(signal_id) => runtime.remove_change_effect(signal_id, self.effect_id),
+                None => runtime.remove_subscription(self.effect_id),
+            }
+        }
+    }
+}
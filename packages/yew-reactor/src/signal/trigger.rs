@@ -0,0 +1,147 @@
+use super::{id::SignalId, Runtime};
+use std::{
+    fmt::{Display, Debug, Formatter, Result as FmtResult},
+    sync::atomic::{AtomicBool, Ordering},
+    sync::Arc,
+};
+
+/// Signal without value, used to re-run effects when a non-reactive state changes
+pub struct Trigger {
+    runtime:    Arc<Runtime>,
+    id:         SignalId,
+    registered: AtomicBool,
+}
+
+impl Trigger {
+    pub(super) fn new(runtime: Arc<Runtime>, id: SignalId) -> Self {
+        runtime.inc_signal_ref(id);
+
+        Self {
+            runtime,
+            id,
+            registered: AtomicBool::new(false),
+        }
+    }
+
+    pub fn id(&self) -> String {
+        self.id.to_string()
+    }
+
+    pub fn runtime(&self) -> Arc<Runtime> {
+        Arc::clone(&self.runtime)
+    }
+
+    pub(super) fn signal_id(&self) -> SignalId {
+        self.id
+    }
+
+    pub fn track(&self) {
+        let runtime = self.runtime();
+
+        // add subscribers
+        if runtime.add_subscriber(self.id) && !self.registered.fetch_or(true, Ordering::SeqCst) {
+            runtime.dec_signal_ref(self.id);
+        }
+    }
+
+    pub fn notify(&self) {
+        let runtime = self.runtime();
+        let id = self.id;
+
+        runtime.check_not_disposed();
+
+        // notify subscribers
+        self.runtime().defer(move || {
+            runtime.notify_subscribers(id);
+        });
+    }
+}
+
+impl Display for Trigger {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "Trigger[{}]", self.id.id())
+    }
+}
+
+impl Debug for Trigger {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "Trigger[{}]", self.id.id())
+    }
+}
+
+impl PartialEq for Trigger {
+    fn eq(&self, other: &Self) -> bool {
+        // deterministic ids are only unique within a runtime
+        self.runtime == other.runtime && self.id == other.id
+    }
+}
+
+impl Eq for Trigger {}
+
+impl Clone for Trigger {
+    fn clone(&self) -> Self {
+        Self::new(self.runtime(), self.id)
+    }
+}
+
+impl Drop for Trigger {
+    fn drop(&mut self) {
+        // a handle released by an effect does not hold a reference anymore
+        if !self.registered.load(Ordering::SeqCst) {
+            self.runtime.clean_signal(self.id);
+        }
+    }
+}
+
+// no-coverage:start
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signal::tests::create_runtime;
+    use std::{sync::atomic::AtomicUsize, cell::RefCell, rc::Rc};
+
+    #[test]
+    fn test_trigger() {
+        let rt = create_runtime();
+        let trigger = Arc::clone(&rt).create_trigger();
+        let cache = Rc::new(RefCell::new(1));
+        let sum = Arc::new(AtomicUsize::new(0));
+
+        {
+            let trigger = trigger.clone();
+            let cache = Rc::clone(&cache);
+            let sum = Arc::clone(&sum);
+
+            rt.create_effect(move || {
+                trigger.track();
+                sum.fetch_add(*cache.borrow(), Ordering::SeqCst);
+            });
+        }
+
+        assert_eq!(sum.load(Ordering::SeqCst), 1, "effect should be run immediately");
+
+        *cache.borrow_mut() = 10;
+        trigger.notify();
+
+        assert_eq!(sum.load(Ordering::SeqCst), 11, "effect should be run when notified");
+    }
+
+    #[test]
+    fn test_trigger_without_value() {
+        let rt = create_runtime();
+        let trigger = Arc::clone(&rt).create_trigger();
+
+        assert!(
+            rt.find_value(trigger.id).is_none(),
+            "trigger should not allocate a value"
+        );
+
+        assert_eq!(trigger, trigger.clone(), "clones should be equal");
+        assert_eq!(
+            trigger.to_string(),
+            format!("Trigger[{}]", trigger.id.id()),
+            "trigger should be displayed with its id"
+        );
+    }
+}
+// no-coverage:stop
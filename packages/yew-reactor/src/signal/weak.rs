@@ -0,0 +1,106 @@
+use super::{id::SignalId, Runtime};
+use std::{
+    sync::{Arc, Weak},
+    marker::PhantomData,
+};
+
+/// Handle of a signal which does not keep it alive, used by the tasks feeding a signal
+pub(crate) struct WeakSignal<T: 'static> {
+    runtime: Weak<Runtime>,
+    id:      SignalId,
+    ty:      PhantomData<T>,
+}
+
+impl<T: 'static> WeakSignal<T> {
+    pub(super) fn new(runtime: &Arc<Runtime>, id: SignalId) -> Self {
+        Self {
+            runtime: Arc::downgrade(runtime),
+            id,
+            ty: PhantomData,
+        }
+    }
+
+    fn alive_runtime(&self) -> Option<Arc<Runtime>> {
+        self.runtime
+            .upgrade()
+            .filter(|runtime| !runtime.is_disposed() && runtime.is_signal_alive(self.id))
+    }
+
+    /// Returns `None` when the signal is gone
+    pub(crate) fn version(&self) -> Option<u64> {
+        self.alive_runtime().map(|runtime| runtime.signal_version(self.id))
+    }
+
+    pub(crate) fn update(&self, f: impl FnOnce(&mut T)) -> bool {
+        self.update_if(|v| {
+            f(v);
+            true
+        })
+    }
+
+    /// Returns `false` when the signal is gone or when the function reports no change
+    pub(crate) fn update_if(&self, f: impl FnOnce(&mut T) -> bool) -> bool {
+        let Some(runtime) = self.alive_runtime() else {
+            return false;
+        };
+
+        let id = self.id;
+
+        // set value
+        let changed = {
+            let value_ref = runtime.get_value(&runtime.get_source_id(id));
+            let mut value = value_ref.write().unwrap();
+
+            f(value.downcast_mut::<T>().unwrap())
+        };
+
+        if !changed {
+            return false;
+        }
+
+        // notify subscribers
+        let notifier = Arc::clone(&runtime);
+
+        runtime.bump_version(id);
+        runtime.defer(move || {
+            notifier.notify_subscribers(id);
+        });
+
+        true
+    }
+
+    pub(crate) fn set(&self, value: T) -> bool {
+        self.update(|v| *v = value)
+    }
+}
+
+impl<T: 'static> Clone for WeakSignal<T> {
+    fn clone(&self) -> Self {
+        Self {
+            runtime: Weak::clone(&self.runtime),
+            id:      self.id,
+            ty:      PhantomData,
+        }
+    }
+}
+
+// no-coverage:start
+#[cfg(test)]
+mod tests {
+    use crate::signal::tests::create_runtime;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_weak_signal() {
+        let rt = create_runtime();
+        let signal = Arc::clone(&rt).create_signal(1);
+        let weak = signal.downgrade();
+
+        assert!(weak.set(2), "weak signal should update its signal");
+        assert_eq!(signal.get(), 2, "signal should be updated through the weak signal");
+
+        drop(signal);
+        assert!(!weak.set(3), "weak signal should not update a dropped signal");
+    }
+}
+// no-coverage:stop
@@ -0,0 +1,127 @@
+use crate::signal::{Runtime, Signal};
+use std::sync::Arc;
+
+#[cfg(feature = "derive")]
+pub use yew_reactor_macros::SignalStruct;
+
+/// Reactive counterpart of a state struct, holding one signal per field
+pub trait SignalStruct: Clone + 'static {
+    type Value;
+
+    fn runtime(&self) -> Arc<Runtime>;
+
+    fn snapshot(&self) -> Self::Value;
+
+    /// Updates every field, only the signals whose value has changed are notified
+    fn set_all(&self, value: Self::Value);
+
+    /// Derived signal holding the whole state, recomputed when any field changes
+    fn to_signal(&self) -> Signal<Self::Value>
+    where
+        Self::Value: PartialEq, {
+        let signals = self.clone();
+
+        self.runtime().create_memo(move |_| signals.snapshot())
+    }
+}
+
+/// State struct which can be turned into its signal struct, implemented by `#[derive(SignalStruct)]`
+pub trait IntoSignalStruct: Sized {
+    type Signals: SignalStruct<Value = Self>;
+
+    fn into_signals(self, rt: &Arc<Runtime>) -> Self::Signals;
+}
+
+// no-coverage:start
+#[cfg(all(test, feature = "derive"))]
+mod tests {
+    use super::*;
+    use crate::signal::tests::{count_notifications, create_runtime};
+
+    #[derive(Clone, Debug, PartialEq, SignalStruct)]
+    struct Font {
+        family: String,
+        size:   u32,
+    }
+
+    #[derive(Clone, Debug, PartialEq, SignalStruct)]
+    struct Settings {
+        theme: String,
+        tags:  Vec<String>,
+
+        #[signal_struct(nested)]
+        font: Font,
+    }
+
+    fn settings() -> Settings {
+        Settings {
+            theme: "light".to_string(),
+            tags:  vec!["a".to_string()],
+            font:  Font {
+                family: "serif".to_string(),
+                size:   12,
+            },
+        }
+    }
+
+    #[test]
+    fn test_signal_struct_fields() {
+        let rt = create_runtime();
+        let signals = SettingsSignals::new(&rt, settings());
+
+        assert_eq!(signals.theme.get(), "light", "field should be held by a signal");
+        assert_eq!(
+            signals.font.size.get(),
+            12,
+            "nested field should be held by a nested signal struct"
+        );
+
+        signals.font.size.set(14);
+        signals.tags.update(|tags| tags.push("b".to_string()));
+
+        let snapshot = signals.snapshot();
+
+        assert_eq!(snapshot.font.size, 14, "snapshot should read the nested signals");
+        assert_eq!(snapshot.tags, vec!["a", "b"], "snapshot should read the signals");
+    }
+
+    #[test]
+    fn test_signal_struct_set_all() {
+        let rt = create_runtime();
+        let signals = settings().into_signals(&rt);
+        let theme_runs = count_notifications(&rt, &signals.theme);
+        let family_runs = count_notifications(&rt, &signals.font.family);
+        let size_runs = count_notifications(&rt, &signals.font.size);
+
+        let mut value = settings();
+        value.theme = "dark".to_string();
+        value.font.size = 16;
+
+        signals.set_all(value.clone());
+
+        assert_eq!(signals.snapshot(), value, "all fields should be updated");
+        assert_eq!(theme_runs.get(), 2, "changed field should be notified");
+        assert_eq!(size_runs.get(), 2, "changed nested field should be notified");
+        assert_eq!(family_runs.get(), 1, "unchanged nested field should not be notified");
+    }
+
+    #[test]
+    fn test_signal_struct_to_signal() {
+        let rt = create_runtime();
+        let signals = SettingsSignals::new(&rt, settings());
+        let whole = signals.to_signal();
+
+        signals.font.family.set("mono".to_string());
+
+        assert_eq!(
+            whole.get().font.family,
+            "mono",
+            "derived signal should follow the fields"
+        );
+        assert!(
+            Arc::ptr_eq(&SignalStruct::runtime(&signals), &rt),
+            "signal struct should belong to its runtime"
+        );
+    }
+}
+// no-coverage:stop
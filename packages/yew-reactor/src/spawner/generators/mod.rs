@@ -3,9 +3,15 @@ mod futures;
 #[cfg(feature = "task-spawner")]
 mod task;
 
+#[cfg(feature = "ssr")]
+mod sync;
+
 pub(super) mod default;
 
 pub use self::{futures::FuturesSpawner};
 
 #[cfg(feature = "task-spawner")]
 pub use self::{task::TaskSpawner};
+
+#[cfg(feature = "ssr")]
+pub use self::{sync::SyncSpawner};
@@ -0,0 +1,55 @@
+use crate::spawner::{SpawnGenerator, FutureVoid};
+use futures::task::noop_waker_ref;
+use yew::platform::spawn_local;
+use std::{task::Context, future::Future, pin::Pin};
+
+/// Spawner which polls the futures immediately, suitable for server-side rendering:
+/// a future which is not ready after its first poll is handed over to the local executor of the renderer.
+#[derive(Clone, Debug, Default)]
+pub struct SyncSpawner;
+
+impl SyncSpawner {
+    pub fn new() -> Self {
+        SyncSpawner
+    }
+}
+
+impl SpawnGenerator for SyncSpawner {
+    fn spawn(&self, mut fut: FutureVoid) {
+        let mut cx = Context::from_waker(noop_waker_ref());
+
+        if Pin::new(&mut fut).poll(&mut cx).is_pending() {
+            spawn_local(fut);
+        }
+    }
+}
+
+// no-coverage:start
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    #[test]
+    fn test_spawn() {
+        let value = Arc::new(AtomicUsize::new(0));
+
+        {
+            let value = Arc::clone(&value);
+
+            SyncSpawner::new().spawn(FutureVoid::new(async move {
+                value.fetch_add(1, Ordering::Relaxed);
+            }));
+        }
+
+        assert_eq!(
+            value.load(Ordering::Relaxed),
+            1,
+            "ready future should be executed immediately"
+        );
+    }
+}
+// no-coverage:stop